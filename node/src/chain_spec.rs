@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, RewardTokenConfig,
+	Signature, SudoConfig, SystemConfig, TemplateModuleConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -14,6 +14,25 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
 
+/// Id of the template asset created at genesis.
+const TEMPLATE_ASSET_ID: u32 = 0;
+/// Name of the template asset created at genesis.
+const TEMPLATE_TOKEN_NAME: &str = "Template Token";
+/// Ticker symbol of the template asset created at genesis.
+const TEMPLATE_TOKEN_SYMBOL: &str = "TMPL";
+/// Decimals of the template asset created at genesis.
+const TEMPLATE_TOKEN_DECIMALS: u8 = 12;
+/// Id of the reward asset created at genesis.
+const REWARD_ASSET_ID: u32 = 0;
+/// Name of the reward asset created at genesis.
+const REWARD_TOKEN_NAME: &str = "Reward Token";
+/// Ticker symbol of the reward asset created at genesis.
+const REWARD_TOKEN_SYMBOL: &str = "RWRD";
+/// Decimals of the reward asset created at genesis.
+const REWARD_TOKEN_DECIMALS: u8 = 12;
+/// Template tokens held by each pre-funded account at genesis.
+const TEMPLATE_ENDOWMENT: u128 = 1_000_000 * 10u128.pow(TEMPLATE_TOKEN_DECIMALS as u32);
+
 /// Generate a crypto pair from seed.
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{}", seed), None)
@@ -36,6 +55,17 @@ pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
 	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
+/// Chain properties describing how wallets should display the template token.
+///
+/// The standard `tokenSymbol` and `tokenDecimals` keys describe the native token of
+/// `pallet_balances`, so the template token is described under keys of its own.
+fn chain_properties() -> sc_service::Properties {
+	let mut properties = sc_service::Properties::new();
+	properties.insert("templateTokenSymbol".into(), TEMPLATE_TOKEN_SYMBOL.into());
+	properties.insert("templateTokenDecimals".into(), TEMPLATE_TOKEN_DECIMALS.into());
+	properties
+}
+
 pub fn development_config() -> Result<ChainSpec, String> {
 	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
 
@@ -70,7 +100,7 @@ pub fn development_config() -> Result<ChainSpec, String> {
 		None,
 		None,
 		// Properties
-		None,
+		Some(chain_properties()),
 		// Extensions
 		None,
 	))
@@ -116,9 +146,9 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 		None,
 		// Protocol ID
 		None,
-		// Properties
-		None,
 		None,
+		// Properties
+		Some(chain_properties()),
 		// Extensions
 		None,
 	))
@@ -149,8 +179,45 @@ fn testnet_genesis(
 		},
 		sudo: SudoConfig {
 			// Assign network admin rights.
-			key: Some(root_key),
+			key: Some(root_key.clone()),
 		},
 		transaction_payment: Default::default(),
+		template_module: TemplateModuleConfig {
+			// The sudo account owns and administers the template asset.
+			assets: vec![(TEMPLATE_ASSET_ID, root_key.clone(), root_key.clone())],
+			metadata: vec![(
+				TEMPLATE_ASSET_ID,
+				TEMPLATE_TOKEN_NAME.as_bytes().to_vec(),
+				TEMPLATE_TOKEN_SYMBOL.as_bytes().to_vec(),
+				TEMPLATE_TOKEN_DECIMALS,
+			)],
+			// Pre-funded accounts also hold template tokens.
+			balances: endowed_accounts
+				.iter()
+				.cloned()
+				.map(|k| (TEMPLATE_ASSET_ID, k, TEMPLATE_ENDOWMENT))
+				.collect(),
+			allowances: vec![],
+			something: None,
+			numbers: vec![],
+			// Oracle keys are generated on the nodes, so the sudo account authorizes their
+			// accounts with `set_oracle_authorities` once they exist.
+			oracle_authorities: vec![],
+		},
+		reward_token: RewardTokenConfig {
+			// Rewards are minted by the sudo account, so nobody holds any at genesis.
+			assets: vec![(REWARD_ASSET_ID, root_key.clone(), root_key)],
+			metadata: vec![(
+				REWARD_ASSET_ID,
+				REWARD_TOKEN_NAME.as_bytes().to_vec(),
+				REWARD_TOKEN_SYMBOL.as_bytes().to_vec(),
+				REWARD_TOKEN_DECIMALS,
+			)],
+			balances: vec![],
+			allowances: vec![],
+			something: None,
+			numbers: vec![],
+			oracle_authorities: vec![],
+		},
 	}
 }
@@ -0,0 +1,45 @@
+//! Runtime API definition for the template pallet.
+//!
+//! The API has no instance parameter: a runtime implements it for one instance of the pallet,
+//! usually the default one. Other instances are out of its scope and are read through state
+//! queries instead.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_template::{AssetMetadata, NumberEntry};
+
+sp_api::decl_runtime_apis! {
+	/// Queries of the template pallet's state.
+	pub trait TemplateApi<AssetId, AccountId, BlockNumber> where
+		AssetId: Codec,
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The name, symbol and decimals of `asset`, if it exists.
+		fn metadata(asset: AssetId) -> Option<AssetMetadata<Vec<u8>>>;
+
+		/// The free balance of `who` in `asset`.
+		fn balance_of(asset: AssetId, who: AccountId) -> u128;
+
+		/// How much of `owner`'s `asset` tokens `spender` may move.
+		fn allowance(asset: AssetId, owner: AccountId, spender: AccountId) -> u128;
+
+		/// The total issuance of `asset`.
+		fn total_supply(asset: AssetId) -> u128;
+
+		/// The total balance of `who` in `asset` as of snapshot `id`, if it has been taken.
+		fn balance_of_at(asset: AssetId, who: AccountId, id: u32) -> Option<u128>;
+
+		/// The total issuance of `asset` as of snapshot `id`, if it has been taken.
+		fn total_supply_at(asset: AssetId, id: u32) -> Option<u128>;
+
+		/// The number `who` stored most recently, if any.
+		fn number_of(who: AccountId) -> Option<u32>;
+
+		/// Up to `limit` of the numbers stored by `who`, oldest first, skipping the first `start`.
+		fn numbers(who: AccountId, start: u32, limit: u32) -> Vec<NumberEntry<BlockNumber>>;
+	}
+}
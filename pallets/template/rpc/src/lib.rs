@@ -0,0 +1,282 @@
+//! JSON-RPC methods for the template pallet, served under the `template_` prefix.
+//!
+//! Each method reads the state at the block hash `at`, or at the best block if it is omitted.
+//! Balances are returned as `NumberOrHex` so that clients do not lose precision on `u128`s.
+//!
+//! The methods serve the instance of the pallet the runtime implements `TemplateRuntimeApi` for.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, DeserializeOwned, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, NumberFor},
+};
+
+pub use pallet_template_rpc_runtime_api::TemplateApi as TemplateRuntimeApi;
+
+/// The name, symbol and decimals of an asset.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AssetMetadata {
+	/// The user friendly name of the asset.
+	pub name: Bytes,
+	/// The ticker symbol of the asset.
+	pub symbol: Bytes,
+	/// The number of decimals the asset uses to represent one unit.
+	pub decimals: u8,
+}
+
+impl From<pallet_template_rpc_runtime_api::AssetMetadata<Vec<u8>>> for AssetMetadata {
+	fn from(metadata: pallet_template_rpc_runtime_api::AssetMetadata<Vec<u8>>) -> Self {
+		Self {
+			name: metadata.name.into(),
+			symbol: metadata.symbol.into(),
+			decimals: metadata.decimals,
+		}
+	}
+}
+
+/// A number stored by an account, and the block it was stored in.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NumberEntry<BlockNumber> {
+	/// The stored number.
+	pub value: u32,
+	/// The block in which it was stored.
+	pub block: BlockNumber,
+}
+
+impl<BlockNumber> From<pallet_template_rpc_runtime_api::NumberEntry<BlockNumber>>
+	for NumberEntry<BlockNumber>
+{
+	fn from(entry: pallet_template_rpc_runtime_api::NumberEntry<BlockNumber>) -> Self {
+		Self { value: entry.value, block: entry.block }
+	}
+}
+
+#[rpc(client, server)]
+pub trait TemplateApi<BlockHash, AssetId, AccountId, BlockNumber> {
+	/// The name, symbol and decimals of `asset`, if it exists.
+	#[method(name = "template_metadata")]
+	fn metadata(&self, asset: AssetId, at: Option<BlockHash>) -> RpcResult<Option<AssetMetadata>>;
+
+	/// The free balance of `who` in `asset`.
+	#[method(name = "template_balanceOf")]
+	fn balance_of(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// How much of `owner`'s `asset` tokens `spender` may move.
+	#[method(name = "template_allowance")]
+	fn allowance(
+		&self,
+		asset: AssetId,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<NumberOrHex>;
+
+	/// The total issuance of `asset`.
+	#[method(name = "template_totalSupply")]
+	fn total_supply(&self, asset: AssetId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	/// The total balance of `who` in `asset` as of snapshot `id`, if it has been taken.
+	#[method(name = "template_balanceOfAt")]
+	fn balance_of_at(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The total issuance of `asset` as of snapshot `id`, if it has been taken.
+	#[method(name = "template_totalSupplyAt")]
+	fn total_supply_at(
+		&self,
+		asset: AssetId,
+		id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<NumberOrHex>>;
+
+	/// The number `who` stored most recently, if any.
+	#[method(name = "template_numberOf")]
+	fn number_of(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Option<u32>>;
+
+	/// Up to `limit` of the numbers stored by `who`, oldest first, skipping the first `start`.
+	#[method(name = "template_numbers")]
+	fn numbers(
+		&self,
+		who: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<NumberEntry<BlockNumber>>>;
+}
+
+/// Serves `TemplateApi` by calling into the runtime of `client`.
+pub struct Template<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Template<C, Block> {
+	/// Create a new `Template` querying `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error codes returned by the template RPC.
+pub enum Error {
+	/// The runtime API call failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block> Template<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// The block to query: `at`, or the best block.
+	fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+/// Turn a failed runtime API call into an RPC error, explaining it with `message`.
+fn runtime_error(message: &'static str, e: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(format!("{:?}", e)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, AssetId, AccountId>
+	TemplateApiServer<<Block as BlockT>::Hash, AssetId, AccountId, NumberFor<Block>>
+	for Template<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: TemplateRuntimeApi<Block, AssetId, AccountId, NumberFor<Block>>,
+	AssetId: Codec + DeserializeOwned + Send + Sync + 'static,
+	AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+	fn metadata(
+		&self,
+		asset: AssetId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AssetMetadata>> {
+		self.client
+			.runtime_api()
+			.metadata(&self.block_id(at), asset)
+			.map(|metadata| metadata.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query metadata.", e))
+	}
+
+	fn balance_of(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.client
+			.runtime_api()
+			.balance_of(&self.block_id(at), asset, who)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query balance.", e))
+	}
+
+	fn allowance(
+		&self,
+		asset: AssetId,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NumberOrHex> {
+		self.client
+			.runtime_api()
+			.allowance(&self.block_id(at), asset, owner, spender)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query allowance.", e))
+	}
+
+	fn total_supply(&self, asset: AssetId, at: Option<Block::Hash>) -> RpcResult<NumberOrHex> {
+		self.client
+			.runtime_api()
+			.total_supply(&self.block_id(at), asset)
+			.map(Into::into)
+			.map_err(|e| runtime_error("Unable to query total supply.", e))
+	}
+
+	fn balance_of_at(
+		&self,
+		asset: AssetId,
+		who: AccountId,
+		id: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		self.client
+			.runtime_api()
+			.balance_of_at(&self.block_id(at), asset, who, id)
+			.map(|balance| balance.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query balance at snapshot.", e))
+	}
+
+	fn total_supply_at(
+		&self,
+		asset: AssetId,
+		id: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<NumberOrHex>> {
+		self.client
+			.runtime_api()
+			.total_supply_at(&self.block_id(at), asset, id)
+			.map(|supply| supply.map(Into::into))
+			.map_err(|e| runtime_error("Unable to query total supply at snapshot.", e))
+	}
+
+	fn number_of(&self, who: AccountId, at: Option<Block::Hash>) -> RpcResult<Option<u32>> {
+		self.client
+			.runtime_api()
+			.number_of(&self.block_id(at), who)
+			.map_err(|e| runtime_error("Unable to query number.", e))
+	}
+
+	fn numbers(
+		&self,
+		who: AccountId,
+		start: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<NumberEntry<NumberFor<Block>>>> {
+		self.client
+			.runtime_api()
+			.numbers(&self.block_id(at), who, start, limit)
+			.map(|entries| entries.into_iter().map(Into::into).collect())
+			.map_err(|e| runtime_error("Unable to query numbers.", e))
+	}
+}
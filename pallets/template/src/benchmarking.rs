@@ -4,16 +4,442 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_system::RawOrigin;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks_instance_pallet, whitelisted_caller};
+use frame_support::{
+	pallet_prelude::TransactionSource,
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency, UnfilteredDispatchable},
+	unsigned::ValidateUnsigned,
+	BoundedVec,
+};
+use frame_system::{offchain::AppCrypto, RawOrigin};
+use sp_runtime::{traits::IdentifyAccount, FixedU128, RuntimeAppPublic};
+use sp_std::prelude::*;
 
-benchmarks! {
+const SEED: u32 = 0;
+
+/// Provides a permit signer for the `permit` benchmark, since the pallet cannot produce
+/// `OffchainSignature`s on its own.
+pub trait BenchmarkHelper<AccountId, Signature> {
+	/// An account whose key `sign` can sign with.
+	fn signer() -> AccountId;
+	/// `signer`'s signature over `message`.
+	fn sign(signer: &AccountId, message: &[u8]) -> Signature;
+}
+
+/// Register `AssetId::default()` with `admin` as its owner and admin.
+fn create_default_asset<T: Config<I>, I: 'static>(admin: &T::AccountId) -> T::AssetId {
+	let asset = Template::<T, I>::default_asset();
+	Assets::<T, I>::insert(asset, AssetDetails { owner: admin.clone(), admin: admin.clone() });
+	asset
+}
+
+/// `n` times the smallest balance an account can hold, so that amounts stay above it.
+fn units<T: Config<I>, I: 'static>(n: u128) -> u128 {
+	T::MinBalance::get().max(1).saturating_mul(n)
+}
+
+/// Store `count` numbers for `who`, without reserving their deposits.
+fn fill_numbers<T: Config<I>, I: 'static>(who: &T::AccountId, count: u32) {
+	let block = frame_system::Pallet::<T>::block_number();
+	let numbers = (0..count).map(|value| NumberEntry { value, block }).collect::<Vec<_>>();
+	Numbers::<T, I>::insert(who, BoundedVec::try_from(numbers).unwrap());
+}
+
+/// Store `count` numbers for `who` and reserve their deposits, leaving enough free native
+/// balance for one more.
+fn fill_numbers_with_deposits<T: Config<I>, I: 'static>(who: &T::AccountId, count: u32) {
+	let deposit = T::NumberDeposit::get().saturating_mul(count.into());
+	let free = T::NativeCurrency::minimum_balance()
+		.saturating_add(T::NumberDeposit::get())
+		.saturating_add(deposit);
+	T::NativeCurrency::make_free_balance_be(who, free);
+	T::NativeCurrency::reserve(who, deposit).unwrap();
+	NumberDeposits::<T, I>::insert(who, deposit);
+	fill_numbers::<T, I>(who, count);
+}
+
+/// Generate an oracle key in the keystore and return its public part.
+fn generate_oracle_key<T: Config<I>, I: 'static>() -> T::Public {
+	type OracleCrypto<T, I> = <T as Config<I>>::AuthorityId;
+	let key =
+		<OracleCrypto<T, I> as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::generate_pair(
+			None,
+		);
+	<OracleCrypto<T, I> as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key).into()
+}
+
+/// Fill `OracleAuthorities` with `MaxOracleAuthorities` accounts, `who` last, so that finding it
+/// scans the whole list.
+fn authorize_oracle<T: Config<I>, I: 'static>(who: T::AccountId) {
+	let others = T::MaxOracleAuthorities::get().saturating_sub(1);
+	let mut authorities = (0..others).map(|i| account("authority", i, SEED)).collect::<Vec<_>>();
+	authorities.push(who);
+	OracleAuthorities::<T, I>::put(BoundedVec::try_from(authorities).unwrap());
+}
+
+benchmarks_instance_pallet! {
 	do_something {
 		let s in 0 .. 100;
 		let caller: T::AccountId = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller), s)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(Something::<T, I>::get(), Some(s));
+	}
+
+	// The caller's history has room for exactly one more number.
+	put_number {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_numbers_with_deposits::<T, I>(&caller, T::MaxNumbers::get() - 1);
+	}: _(RawOrigin::Signed(caller.clone()), 42)
+	verify {
+		assert_eq!(Template::<T, I>::numbers(&caller).len() as u32, T::MaxNumbers::get());
+	}
+
+	// Removing the oldest of a full history shifts every other entry.
+	remove_number {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_numbers_with_deposits::<T, I>(&caller, T::MaxNumbers::get());
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(Template::<T, I>::numbers(&caller).len() as u32, T::MaxNumbers::get() - 1);
+	}
+
+	clear_numbers {
+		let caller: T::AccountId = whitelisted_caller();
+		fill_numbers_with_deposits::<T, I>(&caller, T::MaxNumbers::get());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!Numbers::<T, I>::contains_key(&caller));
+		assert!(!NumberDeposits::<T, I>::contains_key(&caller));
+	}
+
+	// `who` was killed with a full history and an unreleased deposit record.
+	reap_numbers {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		fill_numbers::<T, I>(&who, T::MaxNumbers::get());
+		NumberDeposits::<T, I>::insert(&who, T::NumberDeposit::get());
+	}: _(RawOrigin::Signed(caller), who.clone())
+	verify {
+		assert!(!Numbers::<T, I>::contains_key(&who));
+		assert!(!NumberDeposits::<T, I>::contains_key(&who));
+	}
+
+	create_asset {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = Template::<T, I>::default_asset();
+		let origin = T::CreateOrigin::successful_origin();
+		let call = Call::<T, I>::create_asset { asset, owner: owner.clone(), admin: owner.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		let details = AssetDetails { owner: owner.clone(), admin: owner };
+		assert_eq!(Template::<T, I>::asset(asset), Some(details));
+	}
+
+	set_asset_admin {
+		let caller: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+	}: _(RawOrigin::Signed(caller), asset, admin.clone())
+	verify {
+		assert_eq!(Template::<T, I>::asset(asset).map(|details| details.admin), Some(admin));
+	}
+
+	set_metadata {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_default_asset::<T, I>(&caller);
+		let name = vec![0u8; T::StringLimit::get() as usize];
+		let symbol = vec![1u8; T::StringLimit::get() as usize];
+	}: _(RawOrigin::Signed(caller), asset, name.clone(), symbol, 12)
+	verify {
+		assert_eq!(Template::<T, I>::metadata(asset).name.into_inner(), name);
+	}
+
+	// Raising a balance as the asset admin: the privileged origin is tried first and the supply
+	// is overflow-checked. Here and below, balances and supplies that change are the first to do
+	// so since the latest snapshot, which records them.
+	set_balances {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_mint(asset, &who, units::<T, I>(1))?;
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller), asset, who.clone(), units::<T, I>(10))
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &who), units::<T, I>(10));
+		assert_eq!(Template::<T, I>::total_supply(asset), units::<T, I>(10));
+	}
+
+	mint {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller), asset, to.clone(), units::<T, I>(10))
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &to), units::<T, I>(10));
+	}
+
+	// Burning the whole balance removes it.
+	burn {
+		let caller: T::AccountId = whitelisted_caller();
+		let from: T::AccountId = account("from", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_mint(asset, &from, units::<T, I>(10))?;
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller), asset, from.clone(), units::<T, I>(10))
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &from), 0);
+		assert_eq!(Template::<T, I>::total_supply(asset), 0);
+	}
+
+	// A sender with a vesting lock pays a new recipient.
+	transfers {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_mint(asset, &caller, units::<T, I>(10))?;
+		VestingLocks::<T, I>::insert(asset, &caller, units::<T, I>(5));
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller.clone()), asset, to.clone(), units::<T, I>(5))
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &caller), units::<T, I>(5));
+		assert_eq!(Template::<T, I>::balance_of(asset, &to), units::<T, I>(5));
+	}
+
+	transfer_batch {
+		let b in 1 .. T::MaxBatchSize::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_mint(asset, &caller, units::<T, I>(b.into()))?;
+		let transfers: Vec<(T::AccountId, u128)> =
+			(0 .. b).map(|i| (account("recipient", i, SEED), units::<T, I>(1))).collect();
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller.clone()), asset, transfers)
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &caller), 0);
+	}
+
+	approve {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), asset, spender.clone(), 1_000)
+	verify {
+		assert_eq!(Template::<T, I>::allowance(asset, &caller, &spender), 1_000);
+	}
+
+	permit {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner = T::BenchmarkHelper::signer();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let asset = create_default_asset::<T, I>(&owner);
+		let deadline = frame_system::Pallet::<T>::block_number();
+		let payload = Template::<T, I>::permit_payload(asset, &owner, &spender, 1_000, 0, deadline);
+		let signature = T::BenchmarkHelper::sign(&owner, &payload);
+	}: _(RawOrigin::Signed(caller), asset, owner.clone(), spender.clone(), 1_000, deadline, signature)
+	verify {
+		assert_eq!(Template::<T, I>::allowance(asset, &owner, &spender), 1_000);
+		assert_eq!(Template::<T, I>::permit_nonce(&owner), 1);
+	}
+
+	increase_allowance {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_approve(asset, &caller, &spender, 1_000);
+	}: _(RawOrigin::Signed(caller.clone()), asset, spender.clone(), 1_000)
+	verify {
+		assert_eq!(Template::<T, I>::allowance(asset, &caller, &spender), 2_000);
+	}
+
+	decrease_allowance {
+		let caller: T::AccountId = whitelisted_caller();
+		let spender: T::AccountId = account("spender", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		Template::<T, I>::do_approve(asset, &caller, &spender, 1_000);
+	}: _(RawOrigin::Signed(caller.clone()), asset, spender.clone(), 400)
+	verify {
+		assert_eq!(Template::<T, I>::allowance(asset, &caller, &spender), 600);
+	}
+
+	// A spender moves part of its allowance from a vesting owner to a new recipient.
+	transfer_from {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let asset = create_default_asset::<T, I>(&owner);
+		Template::<T, I>::do_mint(asset, &owner, units::<T, I>(10))?;
+		VestingLocks::<T, I>::insert(asset, &owner, units::<T, I>(5));
+		Template::<T, I>::do_approve(asset, &owner, &caller, units::<T, I>(10));
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller.clone()), asset, owner.clone(), to.clone(), units::<T, I>(5))
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &to), units::<T, I>(5));
+		assert_eq!(Template::<T, I>::allowance(asset, &owner, &caller), units::<T, I>(5));
+	}
+
+	freeze {
+		let who: T::AccountId = account("who", 0, SEED);
+		let asset = create_default_asset::<T, I>(&who);
+		let origin = T::FreezeOrigin::successful_origin();
+		let call = Call::<T, I>::freeze { asset, who: who.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Template::<T, I>::is_frozen(asset, &who));
+	}
+
+	thaw {
+		let who: T::AccountId = account("who", 0, SEED);
+		let asset = create_default_asset::<T, I>(&who);
+		FrozenAccounts::<T, I>::insert(asset, &who, true);
+		let origin = T::FreezeOrigin::successful_origin();
+		let call = Call::<T, I>::thaw { asset, who: who.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Template::<T, I>::is_frozen(asset, &who));
+	}
+
+	pause {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = create_default_asset::<T, I>(&owner);
+		let origin = T::FreezeOrigin::successful_origin();
+		let call = Call::<T, I>::pause { asset };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Template::<T, I>::is_paused(asset));
+	}
+
+	unpause {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = create_default_asset::<T, I>(&owner);
+		PausedAssets::<T, I>::insert(asset, true);
+		let origin = T::FreezeOrigin::successful_origin();
+		let call = Call::<T, I>::unpause { asset };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!Template::<T, I>::is_paused(asset));
+	}
+
+	// The target already has all but one of its vesting schedules.
+	vested_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		let asset = create_default_asset::<T, I>(&caller);
+		let locked = T::MinVestedTransfer::get().max(units::<T, I>(1));
+		let schedule = VestingInfo { locked, per_block: 1, starting_block: 0u32.into() };
+		let existing = vec![schedule.clone(); T::MaxVestingSchedules::get() as usize - 1];
+		Vesting::<T, I>::insert(asset, &target, BoundedVec::try_from(existing).unwrap());
+		Template::<T, I>::do_mint(asset, &caller, locked)?;
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::Signed(caller), asset, target.clone(), schedule)
+	verify {
+		assert_eq!(
+			Template::<T, I>::vesting(asset, &target).map(|schedules| schedules.len() as u32),
+			Some(T::MaxVestingSchedules::get())
+		);
+	}
+
+	// The caller has the maximum number of schedules, none of which has completed.
+	vest {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_default_asset::<T, I>(&caller);
+		let locked = T::MinVestedTransfer::get().max(2);
+		let schedule = VestingInfo { locked, per_block: 1, starting_block: 0u32.into() };
+		let schedules = vec![schedule; T::MaxVestingSchedules::get() as usize];
+		Vesting::<T, I>::insert(asset, &caller, BoundedVec::try_from(schedules).unwrap());
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), asset)
+	verify {
+		assert_eq!(
+			Template::<T, I>::vesting_locks(asset, &caller),
+			(locked - 1) * T::MaxVestingSchedules::get() as u128
+		);
+	}
+
+	set_fee_rate {
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = create_default_asset::<T, I>(&owner);
+		let origin = T::AdminOrigin::successful_origin();
+		let call = Call::<T, I>::set_fee_rate { asset, rate: Some(FixedU128::from(2)) };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Template::<T, I>::fee_rate(asset), Some(FixedU128::from(2)));
+	}
+
+	// `dest` has claimed before and the block already holds a claim.
+	faucet_claim {
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let owner: T::AccountId = account("owner", 0, SEED);
+		let asset = T::FaucetAsset::get();
+		Assets::<T, I>::insert(asset, AssetDetails { owner: owner.clone(), admin: owner });
+		let now = T::FaucetCooldown::get();
+		frame_system::Pallet::<T>::set_block_number(now);
+		LastFaucetClaim::<T, I>::insert(&dest, T::BlockNumber::from(0u32));
+		FaucetClaims::<T, I>::put((now, 0));
+		Template::<T, I>::do_snapshot()?;
+	}: _(RawOrigin::None, dest.clone())
+	verify {
+		assert_eq!(Template::<T, I>::balance_of(asset, &dest), T::FaucetAmount::get());
+		assert_eq!(LastFaucetClaim::<T, I>::get(&dest), Some(now));
+	}
+
+	snapshot {
+		let origin = T::AdminOrigin::successful_origin();
+		let call = Call::<T, I>::snapshot {};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Template::<T, I>::current_snapshot(), 1);
+	}
+
+	submit_oracle_value {
+		let caller: T::AccountId = whitelisted_caller();
+		authorize_oracle::<T, I>(caller.clone());
+	}: _(RawOrigin::Signed(caller), 42)
+	verify {
+		assert_eq!(Template::<T, I>::oracle_value(), Some(42));
+	}
+
+	submit_oracle_value_unsigned {
+		let public = generate_oracle_key::<T, I>();
+		authorize_oracle::<T, I>(public.clone().into_account());
+		let payload = OraclePayload {
+			public: public.clone(),
+			block_number: frame_system::Pallet::<T>::block_number(),
+			value: 42,
+		};
+		let signature =
+			<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::sign(&payload.encode(), public)
+				.unwrap();
+		let call = Call::<T, I>::submit_oracle_value_unsigned { payload, _signature: signature };
+	}: {
+		// Blocks validate unsigned transactions again on import, checking the signature.
+		Template::<T, I>::validate_unsigned(TransactionSource::InBlock, &call)
+			.map_err(|_| "invalid oracle payload")?;
+		call.dispatch_bypass_filter(RawOrigin::None.into())?;
+	}
+	verify {
+		assert_eq!(Template::<T, I>::oracle_value(), Some(42));
+	}
+
+	set_oracle_authorities {
+		let a in 0 .. T::MaxOracleAuthorities::get();
+		let authorities = (0..a).map(|i| account("authority", i, SEED)).collect::<Vec<_>>();
+		let origin = T::AdminOrigin::successful_origin();
+		let call = Call::<T, I>::set_oracle_authorities { authorities: authorities.clone() };
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(Template::<T, I>::oracle_authorities().into_inner(), authorities);
+	}
+
+	// The stored value is incremented rather than missing.
+	cause_error {
+		let caller: T::AccountId = whitelisted_caller();
+		Something::<T, I>::put(1);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert_eq!(Something::<T, I>::get(), Some(2));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,567 @@
+//! Implementation of the `Currency`, `ReservableCurrency`, `NamedReservableCurrency` and
+//! `LockableCurrency` traits for the template token, so the pallet can be plugged into any
+//! `Config` that expects a currency. It operates on the `AssetId::default()` asset.
+//!
+//! `MinBalance` is the existential deposit: an account exists once it holds at least that much,
+//! and balances that drop below it are removed as dust.
+//!
+//! Locks apply to every withdrawal, whatever `WithdrawReasons` they are set with, and overlap:
+//! the largest lock, or the vesting lock if larger, is what cannot be withdrawn.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Imbalance, LockIdentifier, LockableCurrency,
+		NamedReservableCurrency, ReservableCurrency, SignedImbalance, WithdrawReasons,
+	},
+};
+use sp_std::vec::Vec;
+
+pub use imbalances::{NegativeImbalance, PositiveImbalance};
+
+// Wrapping these imbalances in a private module is necessary to ensure absolute privacy of the
+// inner member.
+mod imbalances {
+	use super::*;
+	use frame_support::traits::{SameOrOther, TryDrop};
+	use sp_std::{marker::PhantomData, mem};
+
+	/// Opaque, move-only struct with private fields that serves as a token denoting that funds
+	/// have been created without any equal and opposite accounting.
+	#[must_use]
+	#[derive(RuntimeDebug, PartialEq, Eq)]
+	pub struct PositiveImbalance<T: Config<I>, I: 'static = ()>(u128, PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> PositiveImbalance<T, I> {
+		/// Create a new positive imbalance from a balance.
+		pub fn new(amount: u128) -> Self {
+			PositiveImbalance(amount, PhantomData)
+		}
+	}
+
+	/// Opaque, move-only struct with private fields that serves as a token denoting that funds
+	/// have been destroyed without any equal and opposite accounting.
+	#[must_use]
+	#[derive(RuntimeDebug, PartialEq, Eq)]
+	pub struct NegativeImbalance<T: Config<I>, I: 'static = ()>(u128, PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> NegativeImbalance<T, I> {
+		/// Create a new negative imbalance from a balance.
+		pub fn new(amount: u128) -> Self {
+			NegativeImbalance(amount, PhantomData)
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> TryDrop for PositiveImbalance<T, I> {
+		fn try_drop(self) -> Result<(), Self> {
+			self.drop_zero()
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Default for PositiveImbalance<T, I> {
+		fn default() -> Self {
+			Self::zero()
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Imbalance<u128> for PositiveImbalance<T, I> {
+		type Opposite = NegativeImbalance<T, I>;
+
+		fn zero() -> Self {
+			Self::new(0)
+		}
+
+		fn drop_zero(self) -> Result<(), Self> {
+			if self.0 == 0 {
+				Ok(())
+			} else {
+				Err(self)
+			}
+		}
+
+		fn split(self, amount: u128) -> (Self, Self) {
+			let first = self.0.min(amount);
+			let second = self.0 - first;
+
+			mem::forget(self);
+			(Self::new(first), Self::new(second))
+		}
+
+		fn merge(mut self, other: Self) -> Self {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+
+			self
+		}
+
+		fn subsume(&mut self, other: Self) {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+		}
+
+		fn offset(self, other: Self::Opposite) -> SameOrOther<Self, Self::Opposite> {
+			let (a, b) = (self.0, other.0);
+			mem::forget((self, other));
+
+			if a > b {
+				SameOrOther::Same(Self::new(a - b))
+			} else if b > a {
+				SameOrOther::Other(NegativeImbalance::new(b - a))
+			} else {
+				SameOrOther::None
+			}
+		}
+
+		fn peek(&self) -> u128 {
+			self.0
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> TryDrop for NegativeImbalance<T, I> {
+		fn try_drop(self) -> Result<(), Self> {
+			self.drop_zero()
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Default for NegativeImbalance<T, I> {
+		fn default() -> Self {
+			Self::zero()
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Imbalance<u128> for NegativeImbalance<T, I> {
+		type Opposite = PositiveImbalance<T, I>;
+
+		fn zero() -> Self {
+			Self::new(0)
+		}
+
+		fn drop_zero(self) -> Result<(), Self> {
+			if self.0 == 0 {
+				Ok(())
+			} else {
+				Err(self)
+			}
+		}
+
+		fn split(self, amount: u128) -> (Self, Self) {
+			let first = self.0.min(amount);
+			let second = self.0 - first;
+
+			mem::forget(self);
+			(Self::new(first), Self::new(second))
+		}
+
+		fn merge(mut self, other: Self) -> Self {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+
+			self
+		}
+
+		fn subsume(&mut self, other: Self) {
+			self.0 = self.0.saturating_add(other.0);
+			mem::forget(other);
+		}
+
+		fn offset(self, other: Self::Opposite) -> SameOrOther<Self, Self::Opposite> {
+			let (a, b) = (self.0, other.0);
+			mem::forget((self, other));
+
+			if a > b {
+				SameOrOther::Same(Self::new(a - b))
+			} else if b > a {
+				SameOrOther::Other(PositiveImbalance::new(b - a))
+			} else {
+				SameOrOther::None
+			}
+		}
+
+		fn peek(&self) -> u128 {
+			self.0
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Drop for PositiveImbalance<T, I> {
+		/// Basic drop handler will just square up the total supply.
+		fn drop(&mut self) {
+			Pallet::<T, I>::checkpoint_supply(Pallet::<T, I>::default_asset());
+			TotalSupply::<T, I>::mutate(Pallet::<T, I>::default_asset(), |supply| {
+				*supply = supply.saturating_add(self.0)
+			});
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Drop for NegativeImbalance<T, I> {
+		/// Basic drop handler will just square up the total supply.
+		fn drop(&mut self) {
+			Pallet::<T, I>::checkpoint_supply(Pallet::<T, I>::default_asset());
+			TotalSupply::<T, I>::mutate(Pallet::<T, I>::default_asset(), |supply| {
+				*supply = supply.saturating_sub(self.0)
+			});
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// How much of `account`, the balance of `who`, can be slashed: all of it if the balance can
+	/// be removed, or else all but `MinBalance`.
+	fn slashable(who: &T::AccountId, account: &AccountData) -> u128 {
+		match Self::ensure_can_reap(Self::default_asset(), who) {
+			Ok(()) => account.total(),
+			Err(_) => account.total().saturating_sub(T::MinBalance::get().max(1)),
+		}
+	}
+
+	/// Slash up to `value` of the reserved balance of `who`, leaving its `Holds` untouched, and
+	/// return how much was slashed.
+	fn do_slash_reserved(who: &T::AccountId, value: u128) -> u128 {
+		if value == 0 {
+			return 0
+		}
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		let slashed = account.reserved.min(value).min(Self::slashable(who, &account));
+		account.reserved -= slashed;
+		Self::write_account(Self::default_asset(), who, account);
+
+		slashed
+	}
+
+	/// Store `locks` as the locks of `who`, keeping a consumer reference while it has any.
+	fn update_locks(who: &T::AccountId, locks: Vec<BalanceLock>) {
+		let existed = Locks::<T, I>::contains_key(Self::default_asset(), who);
+		if locks.is_empty() {
+			Locks::<T, I>::remove(Self::default_asset(), who);
+			if existed {
+				<frame_system::Pallet<T>>::dec_consumers(who);
+			}
+			return
+		}
+
+		if !existed && <frame_system::Pallet<T>>::inc_consumers(who).is_err() {
+			// The lock is stored all the same: it only restricts withdrawals.
+			log::warn!(
+				target: "runtime::template",
+				"lock placed on {:?} without a consumer reference",
+				who,
+			);
+		}
+		let locks = WeakBoundedVec::force_from(locks, Some("template locks"));
+		Locks::<T, I>::insert(Self::default_asset(), who, locks);
+	}
+}
+
+impl<T: Config<I>, I: 'static> Currency<T::AccountId> for Pallet<T, I> {
+	type Balance = u128;
+	type PositiveImbalance = PositiveImbalance<T, I>;
+	type NegativeImbalance = NegativeImbalance<T, I>;
+
+	fn total_balance(who: &T::AccountId) -> u128 {
+		Balances::<T, I>::get(Self::default_asset(), who).total()
+	}
+
+	fn can_slash(who: &T::AccountId, value: u128) -> bool {
+		Self::balance_of(Self::default_asset(), who) >= value
+	}
+
+	fn total_issuance() -> u128 {
+		TotalSupply::<T, I>::get(Self::default_asset())
+	}
+
+	fn minimum_balance() -> u128 {
+		T::MinBalance::get()
+	}
+
+	fn burn(mut amount: u128) -> Self::PositiveImbalance {
+		if amount == 0 {
+			return PositiveImbalance::zero()
+		}
+		Self::checkpoint_supply(Self::default_asset());
+		TotalSupply::<T, I>::mutate(Self::default_asset(), |supply| {
+			*supply = supply.checked_sub(amount).unwrap_or_else(|| {
+				amount = *supply;
+				0
+			});
+		});
+		PositiveImbalance::new(amount)
+	}
+
+	fn issue(mut amount: u128) -> Self::NegativeImbalance {
+		if amount == 0 {
+			return NegativeImbalance::zero()
+		}
+		Self::checkpoint_supply(Self::default_asset());
+		TotalSupply::<T, I>::mutate(Self::default_asset(), |supply| {
+			*supply = supply.checked_add(amount).unwrap_or_else(|| {
+				amount = u128::MAX - *supply;
+				u128::MAX
+			});
+		});
+		NegativeImbalance::new(amount)
+	}
+
+	fn free_balance(who: &T::AccountId) -> u128 {
+		Self::balance_of(Self::default_asset(), who)
+	}
+
+	fn ensure_can_withdraw(
+		who: &T::AccountId,
+		_amount: u128,
+		_reasons: WithdrawReasons,
+		new_balance: u128,
+	) -> DispatchResult {
+		Self::ensure_can_send(Self::default_asset(), who)?;
+		Self::ensure_unlocked(Self::default_asset(), who, new_balance).map_err(Into::into)
+	}
+
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		value: u128,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		if existence_requirement == ExistenceRequirement::KeepAlive && source != dest {
+			Self::ensure_keeps_alive(Self::default_asset(), source, value)?;
+		}
+		Self::do_transfer(Self::default_asset(), source, dest, value)
+	}
+
+	/// Slash the free balance first, then the reserved balance. A balance that cannot be
+	/// removed, as `ensure_can_reap` tells, is slashed down to `MinBalance` at most.
+	fn slash(who: &T::AccountId, value: u128) -> (Self::NegativeImbalance, u128) {
+		if value == 0 {
+			return (NegativeImbalance::zero(), 0)
+		}
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		let to_slash = value.min(Self::slashable(who, &account));
+		let from_free = account.free.min(to_slash);
+		let from_reserved = account.reserved.min(to_slash - from_free);
+		account.free -= from_free;
+		Self::release_holds(Self::default_asset(), who, account.reserved, from_reserved);
+		account.reserved -= from_reserved;
+		Self::write_account(Self::default_asset(), who, account);
+
+		let slashed = from_free + from_reserved;
+		(NegativeImbalance::new(slashed), value - slashed)
+	}
+
+	fn deposit_into_existing(
+		who: &T::AccountId,
+		value: u128,
+	) -> Result<Self::PositiveImbalance, DispatchError> {
+		if value == 0 {
+			return Ok(PositiveImbalance::zero())
+		}
+		ensure!(
+			Balances::<T, I>::contains_key(Self::default_asset(), who),
+			Error::<T, I>::DeadAccount
+		);
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		account.free = account.free.checked_add(value).ok_or(Error::<T, I>::Overflow)?;
+		Self::write_account(Self::default_asset(), who, account);
+
+		Ok(PositiveImbalance::new(value))
+	}
+
+	/// Deposits below `MinBalance` into an account that holds no tokens are dropped.
+	fn deposit_creating(who: &T::AccountId, value: u128) -> Self::PositiveImbalance {
+		if value == 0 || Self::ensure_can_receive(Self::default_asset(), who, value).is_err() {
+			return PositiveImbalance::zero()
+		}
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		match account.free.checked_add(value) {
+			Some(free) => account.free = free,
+			None => return PositiveImbalance::zero(),
+		}
+		Self::write_account(Self::default_asset(), who, account);
+
+		PositiveImbalance::new(value)
+	}
+
+	fn withdraw(
+		who: &T::AccountId,
+		value: u128,
+		reasons: WithdrawReasons,
+		liveness: ExistenceRequirement,
+	) -> Result<Self::NegativeImbalance, DispatchError> {
+		if value == 0 {
+			return Ok(NegativeImbalance::zero())
+		}
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		account.free = account.free.checked_sub(value).ok_or(Error::<T, I>::InsufficientBalance)?;
+		Self::ensure_can_withdraw(who, value, reasons, account.free)?;
+		if liveness == ExistenceRequirement::KeepAlive {
+			Self::ensure_keeps_alive(Self::default_asset(), who, value)?;
+		}
+		Self::ensure_can_debit(Self::default_asset(), who, &account)?;
+		Self::write_account(Self::default_asset(), who, account);
+
+		Ok(NegativeImbalance::new(value))
+	}
+
+	/// Leaves the balance untouched if the new total would be nonzero but below `MinBalance`, or
+	/// zero for a balance that cannot be removed.
+	fn make_free_balance_be(
+		who: &T::AccountId,
+		balance: u128,
+	) -> SignedImbalance<u128, Self::PositiveImbalance> {
+		let mut account = Balances::<T, I>::get(Self::default_asset(), who);
+		let old = account.free;
+		account.free = balance;
+		if (account.total() > 0 && account.total() < T::MinBalance::get()) ||
+			Self::ensure_can_debit(Self::default_asset(), who, &account).is_err()
+		{
+			return SignedImbalance::Positive(PositiveImbalance::zero())
+		}
+		Self::write_account(Self::default_asset(), who, account);
+
+		if balance >= old {
+			SignedImbalance::Positive(PositiveImbalance::new(balance - old))
+		} else {
+			SignedImbalance::Negative(NegativeImbalance::new(old - balance))
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> ReservableCurrency<T::AccountId> for Pallet<T, I> {
+	fn can_reserve(who: &T::AccountId, value: u128) -> bool {
+		if value == 0 {
+			return true
+		}
+		let free = Self::balance_of(Self::default_asset(), who);
+		Self::ensure_can_send(Self::default_asset(), who).is_ok() &&
+			free.checked_sub(value).map_or(false, |new_free| {
+				Self::ensure_unlocked(Self::default_asset(), who, new_free).is_ok()
+			})
+	}
+
+	fn slash_reserved(who: &T::AccountId, value: u128) -> (Self::NegativeImbalance, u128) {
+		let reserved = Self::reserved_balance_of(Self::default_asset(), who);
+		let slashed = Self::do_slash_reserved(who, value);
+		Self::release_holds(Self::default_asset(), who, reserved, slashed);
+
+		(NegativeImbalance::new(slashed), value - slashed)
+	}
+
+	fn reserved_balance(who: &T::AccountId) -> u128 {
+		Self::reserved_balance_of(Self::default_asset(), who)
+	}
+
+	fn reserve(who: &T::AccountId, value: u128) -> DispatchResult {
+		Self::do_reserve(Self::default_asset(), who, value)
+	}
+
+	fn unreserve(who: &T::AccountId, value: u128) -> u128 {
+		let reserved = Self::reserved_balance_of(Self::default_asset(), who);
+		let remaining = Self::do_unreserve(Self::default_asset(), who, value);
+		Self::release_holds(Self::default_asset(), who, reserved, value - remaining);
+
+		remaining
+	}
+
+	fn repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: u128,
+		status: BalanceStatus,
+	) -> Result<u128, DispatchError> {
+		let reserved = Self::reserved_balance_of(Self::default_asset(), slashed);
+		let remaining = Self::do_repatriate_reserved(
+			Self::default_asset(),
+			slashed,
+			beneficiary,
+			value,
+			status,
+		)?;
+		if slashed != beneficiary || status == BalanceStatus::Free {
+			Self::release_holds(Self::default_asset(), slashed, reserved, value - remaining);
+		}
+
+		Ok(remaining)
+	}
+}
+
+impl<T: Config<I>, I: 'static> NamedReservableCurrency<T::AccountId> for Pallet<T, I> {
+	type ReserveIdentifier = T::HoldReason;
+
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &T::AccountId,
+		value: u128,
+	) -> (Self::NegativeImbalance, u128) {
+		let held = Self::holds((Self::default_asset(), who, id));
+		let slashed = Self::do_slash_reserved(who, held.min(value));
+		Self::set_hold(Self::default_asset(), id, who, held - slashed);
+
+		(NegativeImbalance::new(slashed), value - slashed)
+	}
+
+	fn reserved_balance_named(id: &Self::ReserveIdentifier, who: &T::AccountId) -> u128 {
+		Self::holds((Self::default_asset(), who, id))
+	}
+
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		who: &T::AccountId,
+		value: u128,
+	) -> DispatchResult {
+		Self::do_reserve_named(Self::default_asset(), id, who, value)
+	}
+
+	fn unreserve_named(id: &Self::ReserveIdentifier, who: &T::AccountId, value: u128) -> u128 {
+		Self::do_unreserve_named(Self::default_asset(), id, who, value)
+	}
+
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: u128,
+		status: BalanceStatus,
+	) -> Result<u128, DispatchError> {
+		Self::do_repatriate_reserved_named(
+			Self::default_asset(),
+			id,
+			slashed,
+			beneficiary,
+			value,
+			status,
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> LockableCurrency<T::AccountId> for Pallet<T, I> {
+	type Moment = T::BlockNumber;
+	type MaxLocks = T::MaxLocks;
+
+	fn set_lock(id: LockIdentifier, who: &T::AccountId, amount: u128, reasons: WithdrawReasons) {
+		if amount == 0 || reasons.is_empty() {
+			return Self::remove_lock(id, who)
+		}
+		let mut locks = Locks::<T, I>::get(Self::default_asset(), who).into_inner();
+		match locks.iter_mut().find(|lock| lock.id == id) {
+			Some(lock) => lock.amount = amount,
+			None => locks.push(BalanceLock { id, amount }),
+		}
+		Self::update_locks(who, locks);
+	}
+
+	fn extend_lock(id: LockIdentifier, who: &T::AccountId, amount: u128, reasons: WithdrawReasons) {
+		if amount == 0 || reasons.is_empty() {
+			return
+		}
+		let mut locks = Locks::<T, I>::get(Self::default_asset(), who).into_inner();
+		match locks.iter_mut().find(|lock| lock.id == id) {
+			Some(lock) => lock.amount = lock.amount.max(amount),
+			None => locks.push(BalanceLock { id, amount }),
+		}
+		Self::update_locks(who, locks);
+	}
+
+	fn remove_lock(id: LockIdentifier, who: &T::AccountId) {
+		let mut locks = Locks::<T, I>::get(Self::default_asset(), who).into_inner();
+		locks.retain(|lock| lock.id != id);
+		Self::update_locks(who, locks);
+	}
+}
@@ -0,0 +1,103 @@
+//! Implementations of the `fungible` traits for the template token, operating on the
+//! `AssetId::default()` asset.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::tokens::{fungible, DepositConsequence, WithdrawConsequence},
+};
+
+impl<T: Config<I>, I: 'static> fungible::Inspect<T::AccountId> for Pallet<T, I> {
+	type Balance = u128;
+
+	fn total_issuance() -> u128 {
+		TotalSupply::<T, I>::get(Self::default_asset())
+	}
+
+	fn minimum_balance() -> u128 {
+		T::MinBalance::get()
+	}
+
+	fn balance(who: &T::AccountId) -> u128 {
+		Balances::<T, I>::get(Self::default_asset(), who).total()
+	}
+
+	fn reducible_balance(who: &T::AccountId, keep_alive: bool) -> u128 {
+		if Self::ensure_can_send(Self::default_asset(), who).is_err() {
+			return 0
+		}
+		let spendable = Self::balance_of(Self::default_asset(), who)
+			.saturating_sub(Self::locked_balance(Self::default_asset(), who));
+		if keep_alive || Self::ensure_can_reap(Self::default_asset(), who).is_err() {
+			spendable.min(Self::balance(who).saturating_sub(T::MinBalance::get()))
+		} else {
+			spendable
+		}
+	}
+
+	fn can_deposit(who: &T::AccountId, amount: u128, mint: bool) -> DepositConsequence {
+		if mint && TotalSupply::<T, I>::get(Self::default_asset()).checked_add(amount).is_none() {
+			return DepositConsequence::Overflow
+		}
+		if Self::ensure_can_receive(Self::default_asset(), who, amount).is_err() {
+			return DepositConsequence::BelowMinimum
+		}
+		match Balances::<T, I>::get(Self::default_asset(), who).total().checked_add(amount) {
+			Some(_) => DepositConsequence::Success,
+			None => DepositConsequence::Overflow,
+		}
+	}
+
+	fn can_withdraw(who: &T::AccountId, amount: u128) -> WithdrawConsequence<u128> {
+		if amount == 0 {
+			return WithdrawConsequence::Success
+		}
+		if TotalSupply::<T, I>::get(Self::default_asset()).checked_sub(amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+		if Self::ensure_can_send(Self::default_asset(), who).is_err() {
+			return WithdrawConsequence::Frozen
+		}
+		match Self::balance_of(Self::default_asset(), who).checked_sub(amount) {
+			Some(new_free)
+				if Self::ensure_unlocked(Self::default_asset(), who, new_free).is_err() =>
+				WithdrawConsequence::Frozen,
+			Some(_) => match Self::balance(who) - amount {
+				remaining
+					if remaining < T::MinBalance::get().max(1) &&
+						Self::ensure_can_reap(Self::default_asset(), who).is_err() =>
+					WithdrawConsequence::WouldDie,
+				remaining if remaining > 0 && remaining < T::MinBalance::get() =>
+					WithdrawConsequence::ReducedToZero(remaining),
+				_ => WithdrawConsequence::Success,
+			},
+			None => WithdrawConsequence::NoFunds,
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::Mutate<T::AccountId> for Pallet<T, I> {
+	fn mint_into(who: &T::AccountId, amount: u128) -> DispatchResult {
+		Self::do_mint(Self::default_asset(), who, amount)
+	}
+
+	fn burn_from(who: &T::AccountId, amount: u128) -> Result<u128, DispatchError> {
+		Self::do_burn(Self::default_asset(), who, amount)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::Transfer<T::AccountId> for Pallet<T, I> {
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: u128,
+		keep_alive: bool,
+	) -> Result<u128, DispatchError> {
+		if keep_alive && source != dest {
+			Self::ensure_keeps_alive(Self::default_asset(), source, amount)?;
+		}
+		Self::do_transfer(Self::default_asset(), source, dest, amount)?;
+		Ok(amount)
+	}
+}
@@ -5,6 +5,20 @@
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
 
+mod impl_currency;
+mod impl_fungible;
+pub mod migrations;
+mod oracle;
+mod payment;
+mod types;
+pub mod weights;
+
+pub use impl_currency::{NegativeImbalance, PositiveImbalance};
+pub use oracle::{crypto, OracleError, OraclePayload, KEY_TYPE};
+pub use payment::{ChargeTemplateFee, InitialPayment};
+pub use types::*;
+pub use weights::WeightInfo;
+
 #[cfg(test)]
 mod mock;
 
@@ -13,22 +27,201 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use crate::{
+		AccountData, AssetDetails, AssetMetadata, BalanceLock, NumberEntry, OnDust, OraclePayload,
+		VestingInfo, WeightInfo,
+	};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			BalanceStatus, ConsumerLimits, Currency, GetStorageVersion, PalletInfoAccess,
+			ReservableCurrency,
+		},
+	};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction},
+		pallet_prelude::*,
+	};
+	use sp_runtime::{
+		traits::{Hash, IdentifyAccount, Saturating, Verify, Zero},
+		transaction_validity::TransactionLongevity,
+		FixedU128,
+	};
+	use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+	/// Domain separator prefixed to every permit payload, so a permit signature cannot be
+	/// mistaken for a signature over anything else.
+	pub const PERMIT_DOMAIN: &[u8] = b"pallet-template/permit";
+
+	/// The number of blocks an unsigned faucet claim stays valid in the transaction pool.
+	const FAUCET_CLAIM_LONGEVITY: TransactionLongevity = 5;
+
+	/// The balance type of `Config::NativeCurrency`.
+	pub type NativeBalanceOf<T, I = ()> = <<T as Config<I>>::NativeCurrency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config + CreateSignedTransaction<Call<Self, I>>
+	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Identifier of an asset issued by this pallet.
+		///
+		/// `AssetId::default()` is the asset the single-asset layout is migrated to, and the one
+		/// the `fungible` and `Currency` implementations operate on.
+		type AssetId: Member
+			+ Parameter
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+
+		/// Identifier of a named hold, naming the feature that reserved the funds.
+		type HoldReason: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The maximum number of `LockableCurrency` locks an account should have. More locks can
+		/// be set, but they are not accounted for in weights.
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+
+		/// Origin allowed to create new assets.
+		type CreateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to create new tokens of any asset.
+		type MintOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to destroy tokens of any asset held by any account.
+		type BurnOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to manage any asset, on top of that asset's admin.
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Origin allowed to freeze accounts and pause assets.
+		type FreezeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of an asset's name or symbol.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		/// The smallest balance an account can hold in any asset. Balances that drop below it
+		/// are removed along with the account's provider reference, and their remainder is lost
+		/// as dust.
+		#[pallet::constant]
+		type MinBalance: Get<u128>;
+
+		/// Handler for the dust of removed balances, which is already out of `TotalSupply`.
+		type OnDust: OnDust<Self::AssetId, Self::AccountId>;
+
+		/// Whether `faucet_claim` is available. Production runtimes should set this to `false`.
+		#[pallet::constant]
+		type FaucetEnabled: Get<bool>;
+
+		/// The asset paid out by the faucet.
+		#[pallet::constant]
+		type FaucetAsset: Get<Self::AssetId>;
+
+		/// The amount paid out by a single faucet claim.
+		#[pallet::constant]
+		type FaucetAmount: Get<u128>;
+
+		/// The number of blocks an account has to wait between two faucet claims.
+		#[pallet::constant]
+		type FaucetCooldown: Get<Self::BlockNumber>;
+
+		/// The maximum number of faucet claims included in a single block.
+		#[pallet::constant]
+		type MaxFaucetClaimsPerBlock: Get<u32>;
+
+		/// The smallest amount that can be sent with `vested_transfer`.
+		#[pallet::constant]
+		type MinVestedTransfer: Get<u128>;
+
+		/// The maximum number of vesting schedules an account can have per asset.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The maximum number of entries each account keeps in `Numbers`.
+		#[pallet::constant]
+		type MaxNumbers: Get<u32>;
+
+		/// The native currency that `NumberDeposit`s are reserved from.
+		type NativeCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved for every entry an account adds to `Numbers`.
+		#[pallet::constant]
+		type NumberDeposit: Get<NativeBalanceOf<Self, I>>;
+
+		/// The maximum number of payouts in a single `transfer_batch`.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Off-chain signature over a permit payload.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// The public key that signs permits, identifying the owner's account.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The account credited with transaction fees paid through `ChargeTemplateFee`.
+		#[pallet::constant]
+		type FeeCollector: Get<Self::AccountId>;
+
+		/// Whether this instance runs an oracle. If `false`, the offchain worker never fetches
+		/// values and every oracle submission is rejected.
+		#[pallet::constant]
+		type OracleEnabled: Get<bool>;
+
+		/// The key the offchain worker signs oracle submissions with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+		/// The endpoint the offchain worker fetches oracle values from.
+		type OracleUrl: Get<&'static str>;
+
+		/// The number of blocks that must pass between two oracle values.
+		#[pallet::constant]
+		type OracleInterval: Get<Self::BlockNumber>;
+
+		/// The priority of unsigned oracle submissions.
+		#[pallet::constant]
+		type OracleUnsignedPriority: Get<TransactionPriority>;
+
+		/// Whether the offchain worker submits oracle values in signed transactions, paid for by
+		/// the account of its key, rather than in unsigned ones carrying a signed payload.
+		#[pallet::constant]
+		type SignedOracleSubmissions: Get<bool>;
+
+		/// The maximum number of accounts in `OracleAuthorities`.
+		#[pallet::constant]
+		type MaxOracleAuthorities: Get<u32>;
+
+		/// The number of blocks between two balance snapshots taken automatically, or zero to
+		/// only take them through `snapshot`.
+		#[pallet::constant]
+		type SnapshotPeriod: Get<Self::BlockNumber>;
+
+		/// Signs the permit used by the `permit` benchmark.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<Self::AccountId, Self::OffchainSignature>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	pub struct Pallet<T>(_);
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/v3/runtime/storage
@@ -36,59 +229,574 @@ pub mod pallet {
 	#[pallet::getter(fn something)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/v3/runtime/storage#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type Something<T, I = ()> = StorageValue<_, u32>;
 
+	/// The numbers each account has stored, oldest first.
 	#[pallet::storage]
 	#[pallet::getter(fn numbers)]
-	pub(super) type Numbers<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+	pub(super) type Numbers<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<NumberEntry<T::BlockNumber>, T::MaxNumbers>,
+		ValueQuery,
+	>;
 
+	/// The native deposit each account has reserved for its `Numbers` entries.
+	#[pallet::storage]
+	#[pallet::getter(fn number_deposit)]
+	pub type NumberDeposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, NativeBalanceOf<T, I>, ValueQuery>;
+
+	/// The latest value submitted by the oracle.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_value)]
+	pub type OracleValue<T, I = ()> = StorageValue<_, u32>;
+
+	/// The first block in which a new oracle value is accepted.
+	#[pallet::storage]
+	#[pallet::getter(fn next_oracle_at)]
+	pub type NextOracleAt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	/// The accounts allowed to submit oracle values, set by `AdminOrigin`.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_authorities)]
+	pub type OracleAuthorities<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxOracleAuthorities>, ValueQuery>;
+
+	/// Details of every asset issued by this pallet.
+	#[pallet::storage]
+	#[pallet::getter(fn asset)]
+	pub type Assets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, AssetDetails<T::AccountId>>;
+
+	/// The free and reserved balance of each account, keyed by asset.
+	///
+	/// Only balances of at least `MinBalance` are kept, and each of them gives its account a
+	/// provider reference, so that accounts holding nothing but these tokens exist in
+	/// `frame_system`.
 	#[pallet::storage]
 	#[pallet::getter(fn balances)]
-	pub(super) type Balances<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+	pub(super) type Balances<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		AccountData,
+		ValueQuery,
+	>;
 
+	/// Named holds, keyed by asset, account and reason. Held funds are part of the account's
+	/// reserved balance.
 	#[pallet::storage]
-	#[pallet::getter(fn allowances)]
-	pub type Allowances<T: Config> = StorageDoubleMap<
+	#[pallet::getter(fn holds)]
+	pub type Holds<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::HoldReason>,
+		),
+		u128,
+		ValueQuery,
+	>;
+
+	/// `LockableCurrency` locks on the free balance of each account, keyed by asset.
+	#[pallet::storage]
+	#[pallet::getter(fn locks)]
+	pub type Locks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
 		T::AccountId,
+		WeakBoundedVec<BalanceLock, T::MaxLocks>,
+		ValueQuery,
+	>;
+
+	/// The amount of tokens of each asset in existence. Always equal to the sum of that asset's
+	/// free and reserved `Balances`.
+	///
+	/// Chains that held balances before the supply was tracked have it seeded from the sum of
+	/// those balances by `migrations::v1::MigrateToMultiAsset`.
+	#[pallet::storage]
+	#[pallet::getter(fn total_supply)]
+	pub type TotalSupply<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, u128, ValueQuery>;
+
+	/// Display information of each asset.
+	#[pallet::storage]
+	#[pallet::getter(fn metadata)]
+	pub type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		AssetMetadata<BoundedVec<u8, T::StringLimit>>,
+		ValueQuery,
+	>;
+
+	/// Accounts that may not send tokens of an asset.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub type FrozenAccounts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
 		Blake2_128Concat,
 		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Assets whose transfers and approvals are suspended.
+	#[pallet::storage]
+	#[pallet::getter(fn is_paused)]
+	pub type PausedAssets<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, bool, ValueQuery>;
+
+	/// The block in which each account last claimed from the faucet.
+	#[pallet::storage]
+	pub type LastFaucetClaim<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+	/// The most recent block with faucet claims, and how many claims it contains.
+	#[pallet::storage]
+	pub type FaucetClaims<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::BlockNumber, u32), ValueQuery>;
+
+	/// Vesting schedules of each account, keyed by asset.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting)]
+	pub type Vesting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingInfo<T::BlockNumber>, T::MaxVestingSchedules>,
+	>;
+
+	/// The part of each account's free balance that cannot be sent because it is still vesting,
+	/// as of the last `vested_transfer` or `vest`.
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_locks)]
+	pub type VestingLocks<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// How many tokens of each asset are charged per unit of native fee. Only assets with a rate
+	/// can pay transaction fees.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_rate)]
+	pub type FeeRates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128>;
+
+	/// The nonce each owner's next permit must be signed with.
+	#[pallet::storage]
+	#[pallet::getter(fn permit_nonce)]
+	pub type PermitNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+	/// Allowances keyed by asset, owner and spender.
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
 		u128,
 		OptionQuery,
 	>;
 
+	/// The id of the latest balance snapshot, or zero before the first one.
+	#[pallet::storage]
+	#[pallet::getter(fn current_snapshot)]
+	pub type CurrentSnapshot<T, I = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The block each snapshot was taken in.
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_block)]
+	pub type SnapshotBlocks<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, T::BlockNumber>;
+
+	/// The total balance of each account in each asset as of a snapshot, keyed by asset, account
+	/// and snapshot id.
+	///
+	/// Written lazily: a balance is only recorded under the current snapshot right before it
+	/// first changes after that snapshot was taken.
+	#[pallet::storage]
+	pub type BalanceSnapshots<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		u128,
+		OptionQuery,
+	>;
+
+	/// The `TotalSupply` of each asset as of a snapshot, recorded like `BalanceSnapshots`.
+	#[pallet::storage]
+	pub type SupplySnapshots<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Twox64Concat, u32, u128>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// Assets to create, as `(asset, owner, admin)`.
+		pub assets: Vec<(T::AssetId, T::AccountId, T::AccountId)>,
+		/// Display information of genesis assets, as `(asset, name, symbol, decimals)`.
+		pub metadata: Vec<(T::AssetId, Vec<u8>, Vec<u8>, u8)>,
+		/// Initial balances, as `(asset, who, amount)`. Counted into `TotalSupply`.
+		pub balances: Vec<(T::AssetId, T::AccountId, u128)>,
+		/// Initial allowances, as `(asset, owner, spender, amount)`.
+		pub allowances: Vec<(T::AssetId, T::AccountId, T::AccountId, u128)>,
+		/// Initial value of `Something`.
+		pub something: Option<u32>,
+		/// Initial `Numbers`, as `(who, number)`, stored in block zero in the given order.
+		pub numbers: Vec<(T::AccountId, u32)>,
+		/// Initial `OracleAuthorities`.
+		pub oracle_authorities: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self {
+				assets: Default::default(),
+				metadata: Default::default(),
+				balances: Default::default(),
+				allowances: Default::default(),
+				something: None,
+				numbers: Default::default(),
+				oracle_authorities: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			for (asset, owner, admin) in &self.assets {
+				assert!(!<Assets<T, I>>::contains_key(asset), "Asset id already in use");
+				<Assets<T, I>>::insert(
+					asset,
+					AssetDetails { owner: owner.clone(), admin: admin.clone() },
+				);
+			}
+
+			for (asset, name, symbol, decimals) in &self.metadata {
+				assert!(<Assets<T, I>>::contains_key(asset), "Metadata for an unknown asset");
+				let metadata =
+					Pallet::<T, I>::bounded_metadata(name.clone(), symbol.clone(), *decimals)
+						.expect("Asset name or symbol is too long");
+				<Metadata<T, I>>::insert(asset, metadata);
+			}
+
+			for (asset, who, amount) in &self.balances {
+				assert!(<Assets<T, I>>::contains_key(asset), "Balance in an unknown asset");
+				let supply = Pallet::<T, I>::total_supply(asset)
+					.checked_add(*amount)
+					.expect("Total supply of a genesis asset overflows");
+				let mut account = <Balances<T, I>>::get(asset, who);
+				account.free =
+					account.free.checked_add(*amount).expect("Genesis balance overflows");
+				assert!(account.free >= T::MinBalance::get(), "Genesis balance below MinBalance");
+				<TotalSupply<T, I>>::insert(asset, supply);
+				Pallet::<T, I>::write_account(*asset, who, account);
+			}
+
+			for (asset, owner, spender, amount) in &self.allowances {
+				assert!(<Assets<T, I>>::contains_key(asset), "Allowance in an unknown asset");
+				<Allowances<T, I>>::insert((asset, owner, spender), amount);
+			}
+
+			if let Some(something) = self.something {
+				<Something<T, I>>::put(something);
+			}
+
+			for (who, number) in &self.numbers {
+				<Numbers<T, I>>::try_mutate(who, |numbers| {
+					numbers.try_push(NumberEntry { value: *number, block: Zero::zero() })
+				})
+				.expect("Too many genesis numbers for an account");
+			}
+
+			let authorities =
+				BoundedVec::<_, T::MaxOracleAuthorities>::try_from(self.oracle_authorities.clone())
+					.expect("Too many genesis oracle authorities");
+			<OracleAuthorities<T, I>>::put(authorities);
+		}
+	}
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
+	//
+	// Every event is deposited with one topic per account it names, the hash of that account, so
+	// that clients can look up the events involving an account without decoding whole blocks.
 	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored(u32, T::AccountId),
-		NumberRemoved(u32, T::AccountId),
-		SettedBalances(u128, T::AccountId),
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// `who` set the stored value to `something`.
+		SomethingStored { something: u32, who: T::AccountId },
+		/// `who` appended `value` to its numbers.
+		NumberStored { who: T::AccountId, value: u32 },
+		/// `who` removed `value`, the number at position `index` of its numbers.
+		NumberRemoved { who: T::AccountId, index: u32, value: u32 },
+		/// The free `asset` balance of `who` was set to `free`.
+		BalanceSet { asset: T::AssetId, who: T::AccountId, free: u128 },
+		/// The `asset` balance of `who` dropped below `MinBalance` and was removed, losing the
+		/// remaining `amount` as dust.
+		DustLost { asset: T::AssetId, who: T::AccountId, amount: u128 },
+		/// A new asset was created.
+		AssetCreated { asset: T::AssetId, owner: T::AccountId, admin: T::AccountId },
+		/// The display information of an asset was set.
+		MetadataSet { asset: T::AssetId, name: Vec<u8>, symbol: Vec<u8>, decimals: u8 },
+		/// `who` received `amount` tokens from the faucet.
+		FaucetClaimed { who: T::AccountId, amount: u128 },
+		/// `who` may no longer send `asset`.
+		Frozen { asset: T::AssetId, who: T::AccountId },
+		/// `who` may send `asset` again.
+		Thawed { asset: T::AssetId, who: T::AccountId },
+		/// Transfers and approvals of `asset` are suspended.
+		Paused { asset: T::AssetId },
+		/// Transfers and approvals of `asset` are possible again.
+		Unpaused { asset: T::AssetId },
+		/// The admin of an asset was changed by its owner.
+		AdminChanged { asset: T::AssetId, admin: T::AccountId },
+		/// `amount` tokens were moved from `from` to `to`.
+		Transfer { asset: T::AssetId, from: T::AccountId, to: T::AccountId, amount: u128 },
+		/// `from` paid `total` tokens out to `count` recipients in a single batch.
+		BatchTransferred { asset: T::AssetId, from: T::AccountId, count: u32, total: u128 },
+		/// `owner` allowed `spender` to move up to `amount` of its tokens.
+		Approval { asset: T::AssetId, owner: T::AccountId, spender: T::AccountId, amount: u128 },
+		/// `amount` new tokens were credited to `to`.
+		Minted { asset: T::AssetId, to: T::AccountId, amount: u128 },
+		/// `amount` tokens were destroyed from `from`.
+		Burned { asset: T::AssetId, from: T::AccountId, amount: u128 },
+		/// `amount` of `who`'s free balance was moved to its reserved balance.
+		Reserved { asset: T::AssetId, who: T::AccountId, amount: u128 },
+		/// `amount` of `who`'s reserved balance was moved back to its free balance.
+		Unreserved { asset: T::AssetId, who: T::AccountId, amount: u128 },
+		/// `amount` of `from`'s reserved balance was moved to the free or reserved balance of
+		/// `to`, as given by `destination_status`.
+		ReserveRepatriated {
+			asset: T::AssetId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+			destination_status: BalanceStatus,
+		},
+		/// `amount` tokens were sent to `to` under a new vesting schedule.
+		VestedTransfer { asset: T::AssetId, from: T::AccountId, to: T::AccountId, amount: u128 },
+		/// The amount still vesting for `who` was updated to `unvested`.
+		VestingUpdated { asset: T::AssetId, who: T::AccountId, unvested: u128 },
+		/// All of `who`'s tokens have vested.
+		VestingCompleted { asset: T::AssetId, who: T::AccountId },
+		/// The rate at which `asset` pays transaction fees was set, or cleared if `None`.
+		FeeRateSet { asset: T::AssetId, rate: Option<FixedU128> },
+		/// `who` paid a transaction fee of `actual_fee` in `asset`.
+		FeePaid { asset: T::AssetId, who: T::AccountId, actual_fee: u128 },
+		/// All `count` numbers stored by `who` were removed.
+		NumbersCleared { who: T::AccountId, count: u32 },
+		/// The `count` numbers left behind by the killed account `who` were removed.
+		NumbersReaped { who: T::AccountId, count: u32 },
+		/// The oracle value was set to `value`, by `who` or in an unsigned transaction.
+		OracleValueSubmitted { who: Option<T::AccountId>, value: u32 },
+		/// The accounts allowed to submit oracle values were set to `authorities`.
+		OracleAuthoritiesSet { authorities: Vec<T::AccountId> },
+		/// Balance snapshot `id` was taken.
+		SnapshotTaken { id: u32 },
+	}
+
+	impl<T: Config<I>, I: 'static> Event<T, I> {
+		/// The accounts named by the event, which it is indexed by.
+		fn accounts(&self) -> Vec<&T::AccountId> {
+			match self {
+				Event::SomethingStored { who, .. } |
+				Event::NumberStored { who, .. } |
+				Event::NumberRemoved { who, .. } |
+				Event::BalanceSet { who, .. } |
+				Event::DustLost { who, .. } |
+				Event::FaucetClaimed { who, .. } |
+				Event::Frozen { who, .. } |
+				Event::Thawed { who, .. } |
+				Event::Reserved { who, .. } |
+				Event::Unreserved { who, .. } |
+				Event::VestingUpdated { who, .. } |
+				Event::VestingCompleted { who, .. } |
+				Event::FeePaid { who, .. } |
+				Event::NumbersCleared { who, .. } |
+				Event::NumbersReaped { who, .. } |
+				Event::OracleValueSubmitted { who: Some(who), .. } => vec![who],
+				Event::AssetCreated { owner, admin, .. } => vec![owner, admin],
+				Event::AdminChanged { admin, .. } => vec![admin],
+				Event::Transfer { from, to, .. } |
+				Event::ReserveRepatriated { from, to, .. } |
+				Event::VestedTransfer { from, to, .. } => vec![from, to],
+				Event::BatchTransferred { from, .. } => vec![from],
+				Event::OracleAuthoritiesSet { authorities } => authorities.iter().collect(),
+				Event::Approval { owner, spender, .. } => vec![owner, spender],
+				Event::Minted { to, .. } => vec![to],
+				Event::Burned { from, .. } => vec![from],
+				Event::MetadataSet { .. } |
+				Event::Paused { .. } |
+				Event::Unpaused { .. } |
+				Event::FeeRateSet { .. } |
+				Event::OracleValueSubmitted { who: None, .. } |
+				Event::SnapshotTaken { .. } |
+				Event::__Ignore(..) => Vec::new(),
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Runs after the runtime's own migrations. Storage is migrated by the steps in
+		/// `migrations::Migrations`, which the runtime has to list in its `Executive`; this only
+		/// warns when one of them is missing.
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Self::on_chain_storage_version();
+			if on_chain < STORAGE_VERSION {
+				log::warn!(
+					target: "runtime::template",
+					"storage version {:?} is behind {:?}: a migration is missing from the runtime",
+					on_chain,
+					STORAGE_VERSION,
+				);
+			}
+
+			T::DbWeight::get().reads(1)
+		}
+
+		/// Takes a balance snapshot every `SnapshotPeriod` blocks.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let period = T::SnapshotPeriod::get();
+			if period.is_zero() || !(now % period).is_zero() {
+				return 0
+			}
+
+			if let Err(error) = Self::do_snapshot() {
+				log::warn!(target: "runtime::template", "failed to take a snapshot: {:?}", error);
+			}
+
+			T::WeightInfo::snapshot()
+		}
+
+		/// Fetches and submits a new oracle value once the current interval is over, if the oracle
+		/// is enabled.
+		fn offchain_worker(now: T::BlockNumber) {
+			if !T::OracleEnabled::get() || now < Self::next_oracle_at() {
+				return
+			}
+
+			if let Err(error) = Self::submit_oracle_value_offchain(now) {
+				log::warn!(target: "runtime::template", "failed to submit oracle value: {:?}", error);
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(
+				Self::on_chain_storage_version() == STORAGE_VERSION,
+				"storage version does not match the pallet's"
+			);
+
+			Self::do_try_state()
+		}
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Error names should be descriptive.
 		NoneValue,
 		/// Errors should have helpful documentation associated with them.
 		StorageOverflow,
+		/// The sender does not hold enough tokens to cover the transfer.
+		InsufficientBalance,
+		/// Crediting the recipient would overflow its balance.
+		Overflow,
+		/// The spender's allowance does not cover the requested amount.
+		InsufficientAllowance,
+		/// The asset does not exist.
+		UnknownAsset,
+		/// An asset with this id already exists.
+		AssetExists,
+		/// The signer is not allowed to manage this asset.
+		NoPermission,
+		/// The name or symbol is longer than `StringLimit`.
+		BadMetadata,
+		/// The faucet is disabled on this chain.
+		FaucetDisabled,
+		/// The account claimed from the faucet less than `FaucetCooldown` blocks ago.
+		FaucetCooldown,
+		/// This block already contains `MaxFaucetClaimsPerBlock` faucet claims.
+		FaucetCapReached,
+		/// The sending account is frozen.
+		Frozen,
+		/// The asset is paused.
+		Paused,
+		/// The transfer would spend tokens that are locked or still vesting.
+		BalanceLocked,
+		/// The account has no vesting schedules in this asset.
+		NotVesting,
+		/// The account already has `MaxVestingSchedules` vesting schedules in this asset.
+		AtMaxVestingSchedules,
+		/// The vested amount is below `MinVestedTransfer`.
+		AmountLow,
+		/// The vesting schedule locks nothing or never unlocks.
+		InvalidScheduleParams,
+		/// The batch holds more than `MaxBatchSize` payouts.
+		TooManyTransfers,
+		/// The permit's deadline has passed.
+		PermitExpired,
+		/// The permit is not signed by its owner over the expected payload.
+		BadPermitSignature,
+		/// The account already stores `MaxNumbers` numbers.
+		TooManyNumbers,
+		/// The account stores no number at the given index.
+		NumberNotFound,
+		/// The account still exists, so its numbers cannot be reaped.
+		AccountStillAlive,
+		/// An oracle value was already submitted in the current interval.
+		OracleTooEarly,
+		/// This instance does not run an oracle.
+		OracleDisabled,
+		/// The account is not one of `OracleAuthorities`.
+		NotOracleAuthority,
+		/// More than `MaxOracleAuthorities` oracle authorities were given.
+		TooManyOracleAuthorities,
+		/// The balance of an account that holds none of the asset would start below
+		/// `MinBalance`.
+		BelowMinimum,
+		/// The withdrawal would leave the account's balance below `MinBalance`, removing it.
+		KeepAlive,
+		/// The account holds none of the asset.
+		DeadAccount,
+		/// Removing the balance would take away a provider reference that the account's
+		/// consumers still need.
+		WouldDie,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
 		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(T::WeightInfo::do_something())]
 		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
 			// Check that the extrinsic was signed and get the signer.
 			// This function will return an error if the extrinsic is not signed.
@@ -96,85 +804,1473 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			// Update storage.
-			<Something<T>>::put(something);
+			<Something<T, I>>::put(something);
 
 			// Emit an event.
-			Self::deposit_event(Event::SomethingStored(something, who));
+			Self::deposit_event(Event::SomethingStored { something, who });
 			// Return a successful DispatchResultWithPostInfo
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		/// Append `number` to the numbers stored by the signer, reserving `NumberDeposit` from
+		/// its native balance.
+		///
+		/// Fails with `TooManyNumbers` once the signer stores `MaxNumbers` of them.
+		#[pallet::weight(T::WeightInfo::put_number())]
 		pub fn put_number(origin: OriginFor<T>, number: u32) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let block = <frame_system::Pallet<T>>::block_number();
+
+			let mut numbers = Self::numbers(&who);
+			numbers
+				.try_push(NumberEntry { value: number, block })
+				.map_err(|_| Error::<T, I>::TooManyNumbers)?;
+
+			let deposit = T::NumberDeposit::get();
+			T::NativeCurrency::reserve(&who, deposit)?;
+			<NumberDeposits<T, I>>::mutate(&who, |held| *held = held.saturating_add(deposit));
+			<Numbers<T, I>>::insert(&who, numbers);
+
+			Self::deposit_event(Event::NumberStored { who, value: number });
+
+			Ok(())
+		}
+
+		/// Remove the number the signer stored at position `index`, shifting later ones down,
+		/// and return its share of the signer's reserved deposit.
+		///
+		/// Fails with `NoneValue` if the signer stores no numbers at all.
+		#[pallet::weight(T::WeightInfo::remove_number())]
+		pub fn remove_number(origin: OriginFor<T>, index: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			<Numbers<T>>::insert(who.clone(), number);
+			let mut numbers = Self::numbers(&who);
+			ensure!(!numbers.is_empty(), Error::<T, I>::NoneValue);
+			ensure!((index as usize) < numbers.len(), Error::<T, I>::NumberNotFound);
 
-			Self::deposit_event(Event::SomethingStored(number, who));
+			// Every entry holds an equal share of the deposit, so that the last one to go
+			// returns whatever is left even if `NumberDeposit` changed in the meantime.
+			let held = Self::number_deposit(&who);
+			let refund = held / NativeBalanceOf::<T, I>::from(numbers.len() as u32);
+			Self::release_number_deposit(&who, refund);
+			let entry = numbers.remove(index as usize);
+
+			if numbers.is_empty() {
+				<Numbers<T, I>>::remove(&who);
+			} else {
+				<Numbers<T, I>>::insert(&who, numbers);
+			}
+
+			Self::deposit_event(Event::NumberRemoved { who, index, value: entry.value });
 
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads(1))]
-		pub fn remove_number(origin: OriginFor<T>) -> DispatchResult {
+		/// Remove every number stored by the signer.
+		#[pallet::weight(T::WeightInfo::clear_numbers())]
+		pub fn clear_numbers(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let own_number = <Numbers<T>>::get(who.clone());
+			let count = <Numbers<T, I>>::take(&who).len() as u32;
+			Self::release_number_deposit(&who, Self::number_deposit(&who));
+
+			Self::deposit_event(Event::NumbersCleared { who, count });
+
+			Ok(())
+		}
+
+		/// Remove the numbers of `who` after its account was killed, releasing whatever is
+		/// left of its deposit.
+		///
+		/// The origin must be root or signed by anyone; the call only succeeds once `who` no
+		/// longer exists in `frame_system`.
+		#[pallet::weight(T::WeightInfo::reap_numbers())]
+		pub fn reap_numbers(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			frame_system::ensure_signed_or_root(origin)?;
+			ensure!(
+				!<frame_system::Pallet<T>>::account_exists(&who),
+				Error::<T, I>::AccountStillAlive
+			);
+
+			let count = <Numbers<T, I>>::take(&who).len() as u32;
+			ensure!(count > 0, Error::<T, I>::NoneValue);
+			Self::release_number_deposit(&who, Self::number_deposit(&who));
 
-			<Numbers<T>>::remove(who.clone());
+			Self::deposit_event(Event::NumbersReaped { who, count });
 
-			Self::deposit_event(Event::NumberRemoved(own_number, who));
+			Ok(())
+		}
+
+		/// Register a new asset `asset`, owned by `owner` and administered by `admin`.
+		///
+		/// The origin must be `CreateOrigin`.
+		#[pallet::weight(T::WeightInfo::create_asset())]
+		pub fn create_asset(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			owner: T::AccountId,
+			admin: T::AccountId,
+		) -> DispatchResult {
+			T::CreateOrigin::ensure_origin(origin)?;
+			ensure!(!<Assets<T, I>>::contains_key(asset), Error::<T, I>::AssetExists);
+
+			<Assets<T, I>>::insert(
+				asset,
+				AssetDetails { owner: owner.clone(), admin: admin.clone() },
+			);
+
+			Self::deposit_event(Event::AssetCreated { asset, owner, admin });
 
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn set_balances(origin: OriginFor<T>, amount: u128) -> DispatchResult {
+		/// Hand the administration of `asset` over to `admin`.
+		///
+		/// The origin must be signed by the owner of `asset`.
+		#[pallet::weight(T::WeightInfo::set_asset_admin())]
+		pub fn set_asset_admin(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			admin: T::AccountId,
+		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Balances<T>>::insert(who.clone(), amount);
+			<Assets<T, I>>::try_mutate(asset, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T, I>::UnknownAsset)?;
+				ensure!(details.owner == who, Error::<T, I>::NoPermission);
+				details.admin = admin.clone();
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::AdminChanged { asset, admin });
+
+			Ok(())
+		}
+
+		/// Set the name, symbol and decimals shown for `asset`.
+		///
+		/// The origin must be `AdminOrigin` or signed by the admin of `asset`.
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResult {
+			Self::ensure_admin_or::<T::AdminOrigin>(origin, asset)?;
 
-			Self::deposit_event(Event::SettedBalances(amount, who));
+			let metadata = Self::bounded_metadata(name.clone(), symbol.clone(), decimals)?;
+			<Metadata<T, I>>::insert(asset, metadata);
+
+			Self::deposit_event(Event::MetadataSet { asset, name, symbol, decimals });
 
 			Ok(())
 		}
 
-		// #[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		// pub fn approve(
-		// 	origin: OriginFor<T>,
-		// 	spender: OriginFor<T>,
-		// 	amount: u128,
-		// ) -> DispatchResult {
-		// 	let who = ensure_signed(origin)?;
-		// 	let _spender = ensure_none(spender)?;
+		/// Force the free balance of `who` in `asset` to `amount`, adjusting `TotalSupply` by the
+		/// difference. The reserved balance is left untouched.
+		///
+		/// Raising a balance requires `MintOrigin`; lowering it requires `BurnOrigin`. The admin of
+		/// `asset` may do either. A balance lowered below `MinBalance` is removed as dust.
+		#[pallet::weight(T::WeightInfo::set_balances())]
+		pub fn set_balances(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			who: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let mut account = <Balances<T, I>>::get(asset, &who);
+			let old = account.free;
+			if amount > old {
+				Self::ensure_admin_or::<T::MintOrigin>(origin, asset)?;
+				Self::ensure_can_receive(asset, &who, amount - old)?;
+				let supply = Self::total_supply(asset)
+					.checked_add(amount - old)
+					.ok_or(Error::<T, I>::Overflow)?;
+				Self::checkpoint_supply(asset);
+				<TotalSupply<T, I>>::insert(asset, supply);
+			} else {
+				Self::ensure_admin_or::<T::BurnOrigin>(origin, asset)?;
+				Self::ensure_can_debit(asset, &who, &AccountData { free: amount, ..account })?;
+				Self::checkpoint_supply(asset);
+				<TotalSupply<T, I>>::mutate(asset, |supply| {
+					*supply = supply.saturating_sub(old - amount)
+				});
+			}
 
-		// 	<Allowances<T>>::insert(who.clone(), _spender.clone(), amount);
+			account.free = amount;
+			Self::write_account(asset, &who, account);
 
-		// 	Ok(())
-		// }
+			Self::deposit_event(Event::BalanceSet { asset, who, free: amount });
+
+			Ok(())
+		}
+
+		/// Create `amount` new tokens of `asset` and credit them to `to`.
+		///
+		/// The origin must be `MintOrigin` or signed by the admin of `asset`.
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			Self::ensure_admin_or::<T::MintOrigin>(origin, asset)?;
+
+			Self::do_mint(asset, &to, amount)
+		}
+
+		/// Destroy `amount` tokens of `asset` held by `from`.
+		///
+		/// The origin must be `BurnOrigin` or signed by the admin of `asset`.
+		#[pallet::weight(T::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			from: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			Self::ensure_admin_or::<T::BurnOrigin>(origin, asset)?;
+
+			Self::do_burn(asset, &from, amount)
+		}
+
+		/// Move `amount` tokens of `asset` from the signer to `to`.
+		///
+		/// A transfer to oneself leaves balances untouched, but still requires the signer to hold
+		/// `amount` and still emits `Transfer`, so it behaves like any other transfer to callers.
+		#[pallet::weight(T::WeightInfo::transfers())]
+		pub fn transfers(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Self::do_transfer(asset, &from, &to, amount)
+		}
+
+		/// Pay each `(to, amount)` in `transfers` out of the signer's `asset` tokens, emitting a
+		/// `Transfer` per payout and a closing `BatchTransferred`.
+		///
+		/// The batch is all-or-nothing: the signer's balance is checked against the sum of all
+		/// payouts before any of them is made. A new recipient only has to receive `MinBalance`
+		/// across all of its payouts, not in each of them.
+		#[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			transfers: Vec<(T::AccountId, u128)>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				transfers.len() <= T::MaxBatchSize::get() as usize,
+				Error::<T, I>::TooManyTransfers
+			);
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			Self::ensure_can_send(asset, &from)?;
+
+			let total = transfers
+				.iter()
+				.try_fold(0u128, |acc, (_, amount)| acc.checked_add(*amount))
+				.ok_or(Error::<T, I>::Overflow)?;
+			let mut from_account = <Balances<T, I>>::get(asset, &from);
+			let remaining =
+				from_account.free.checked_sub(total).ok_or(Error::<T, I>::InsufficientBalance)?;
+			Self::ensure_unlocked(asset, &from, remaining)?;
+			// Each recipient is credited the sum of its payouts at once, so that it only has to
+			// receive `MinBalance` in total rather than in every payout.
+			let mut received = BTreeMap::<&T::AccountId, u128>::new();
+			for (to, amount) in transfers.iter().filter(|(to, _)| *to != from) {
+				// Cannot overflow: the sum of all payouts was checked above.
+				*received.entry(to).or_default() += *amount;
+			}
+			for (to, amount) in &received {
+				Self::ensure_can_receive(asset, to, *amount)?;
+			}
+
+			// Payouts to the signer itself stay where they are, so that debiting the others
+			// cannot remove its balance just before it is credited again.
+			let paid_to_self = transfers
+				.iter()
+				.filter(|(to, _)| *to == from)
+				.fold(0u128, |acc, (_, amount)| acc + amount);
+			from_account.free = remaining + paid_to_self;
+			Self::ensure_can_debit(asset, &from, &from_account)?;
+			Self::write_account(asset, &from, from_account);
+
+			for (to, amount) in received {
+				// Cannot overflow: once debited, the payouts are part of `TotalSupply` again.
+				let mut account = <Balances<T, I>>::get(asset, to);
+				account.free = account.free.saturating_add(amount);
+				Self::write_account(asset, to, account);
+			}
+
+			let count = transfers.len() as u32;
+			for (to, amount) in transfers {
+				Self::deposit_event(Event::Transfer { asset, from: from.clone(), to, amount });
+			}
+
+			Self::deposit_event(Event::BatchTransferred { asset, from, count, total });
+
+			Ok(())
+		}
+
+		/// Allow `spender` to move up to `amount` of the signer's `asset` tokens, replacing any
+		/// previous allowance. An `amount` of zero revokes the allowance.
+		#[pallet::weight(T::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			spender: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+
+			Self::do_approve(asset, &owner, &spender, amount);
+
+			Ok(())
+		}
+
+		/// Set the allowance `owner` gives `spender` over `asset` to `amount`, authorized by
+		/// `owner`'s off-chain `signature` over `permit_payload` instead of a transaction of its
+		/// own. Anyone can relay the permit and pay its fee.
+		///
+		/// Each permit is signed over the owner's current `PermitNonces` entry, which is then
+		/// bumped, so it can only be used once. It can no longer be used after block `deadline`.
+		#[pallet::weight(T::WeightInfo::permit())]
+		pub fn permit(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: u128,
+			deadline: T::BlockNumber,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= deadline,
+				Error::<T, I>::PermitExpired
+			);
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+
+			let nonce = Self::permit_nonce(&owner);
+			let payload = Self::permit_payload(asset, &owner, &spender, amount, nonce, deadline);
+			ensure!(signature.verify(&payload[..], &owner), Error::<T, I>::BadPermitSignature);
+
+			<PermitNonces<T, I>>::insert(&owner, nonce.saturating_add(1));
+			Self::do_approve(asset, &owner, &spender, amount);
+
+			Ok(())
+		}
+
+		/// Raise the allowance the signer has given to `spender` over `asset` by `amount`.
+		#[pallet::weight(T::WeightInfo::increase_allowance())]
+		pub fn increase_allowance(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			spender: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+
+			let allowance = Self::allowance(asset, &owner, &spender)
+				.checked_add(amount)
+				.ok_or(Error::<T, I>::Overflow)?;
+			Self::do_approve(asset, &owner, &spender, allowance);
+
+			Ok(())
+		}
+
+		/// Lower the allowance the signer has given to `spender` over `asset` by `amount`.
+		#[pallet::weight(T::WeightInfo::decrease_allowance())]
+		pub fn decrease_allowance(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			spender: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+
+			let allowance = Self::allowance(asset, &owner, &spender)
+				.checked_sub(amount)
+				.ok_or(Error::<T, I>::InsufficientAllowance)?;
+			Self::do_approve(asset, &owner, &spender, allowance);
+
+			Ok(())
+		}
+
+		/// Move `amount` of `owner`'s `asset` tokens to `to`, spending the allowance `owner` gave
+		/// the signer.
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			owner: T::AccountId,
+			to: T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+
+			let allowance = Self::allowance(asset, &owner, &spender)
+				.checked_sub(amount)
+				.ok_or(Error::<T, I>::InsufficientAllowance)?;
+			Self::do_transfer(asset, &owner, &to, amount)?;
+			Self::do_approve(asset, &owner, &spender, allowance);
+
+			Ok(())
+		}
+
+		/// Stop `who` from sending `asset`. It can still receive it.
+		///
+		/// The origin must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::freeze())]
+		pub fn freeze(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			who: T::AccountId,
+		) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			<FrozenAccounts<T, I>>::insert(asset, &who, true);
+
+			Self::deposit_event(Event::Frozen { asset, who });
+
+			Ok(())
+		}
+
+		/// Allow a frozen `who` to send `asset` again.
+		///
+		/// The origin must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::thaw())]
+		pub fn thaw(origin: OriginFor<T>, asset: T::AssetId, who: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+
+			<FrozenAccounts<T, I>>::remove(asset, &who);
+
+			Self::deposit_event(Event::Thawed { asset, who });
+
+			Ok(())
+		}
+
+		/// Suspend all transfers, approvals and `transfer_from` calls of `asset`.
+		///
+		/// The origin must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			<PausedAssets<T, I>>::insert(asset, true);
+
+			Self::deposit_event(Event::Paused { asset });
+
+			Ok(())
+		}
+
+		/// Resume transfers and approvals of a paused `asset`.
+		///
+		/// The origin must be `FreezeOrigin`.
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+
+			<PausedAssets<T, I>>::remove(asset);
+
+			Self::deposit_event(Event::Unpaused { asset });
+
+			Ok(())
+		}
+
+		/// Send `schedule.locked` of the signer's `asset` tokens to `target`, locked under
+		/// `schedule`.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			target: T::AccountId,
+			schedule: VestingInfo<T::BlockNumber>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T, I>::AmountLow);
+			ensure!(schedule.is_valid(), Error::<T, I>::InvalidScheduleParams);
+
+			let existing = Self::vesting(asset, &target);
+			let first_schedule = existing.is_none();
+			let mut schedules = existing.unwrap_or_default();
+			schedules
+				.try_push(schedule.clone())
+				.map_err(|_| Error::<T, I>::AtMaxVestingSchedules)?;
+
+			if first_schedule {
+				// `target` may only get a provider from the transfer below, so the consumer
+				// limit is checked directly rather than with `can_inc_consumer`.
+				ensure!(
+					<frame_system::Pallet<T>>::consumers(&target) <
+						<T as frame_system::Config>::MaxConsumers::max_consumers(),
+					DispatchError::TooManyConsumers
+				);
+			}
+
+			Self::do_transfer(asset, &from, &target, schedule.locked)?;
+			if first_schedule {
+				// The schedules lock part of a balance that keeps `target` alive, like a
+				// currency lock does. Cannot fail: the transfer gave `target` a provider if it
+				// had none, and the limit was checked above.
+				<frame_system::Pallet<T>>::inc_consumers(&target)?;
+			}
+			<Vesting<T, I>>::insert(asset, &target, schedules);
+			Self::deposit_event(Event::VestedTransfer {
+				asset,
+				from,
+				to: target.clone(),
+				amount: schedule.locked,
+			});
+
+			Self::do_vest(asset, &target);
+
+			Ok(())
+		}
+
+		/// Unlock the signer's `asset` tokens that have vested by now, and drop the schedules that
+		/// have completed.
+		#[pallet::weight(T::WeightInfo::vest())]
+		pub fn vest(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Vesting<T, I>>::contains_key(asset, &who), Error::<T, I>::NotVesting);
+
+			Self::do_vest(asset, &who);
+
+			Ok(())
+		}
+
+		/// Let `asset` pay transaction fees at `rate` tokens per unit of native fee, or stop it
+		/// from paying fees if `rate` is `None`.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_fee_rate())]
+		pub fn set_fee_rate(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			rate: Option<FixedU128>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+
+			<FeeRates<T, I>>::set(asset, rate);
+
+			Self::deposit_event(Event::FeeRateSet { asset, rate });
+
+			Ok(())
+		}
+
+		/// Pay `FaucetAmount` of `FaucetAsset` to `dest`.
+		///
+		/// This is an unsigned, feeless transaction so accounts without any funds can claim.
+		/// `validate_unsigned` keeps it from being used for spam: a claim only enters the pool
+		/// when the faucet is enabled, its asset exists and is not paused, `dest` is out of its
+		/// cooldown and the per-block cap has room left, and the pool holds at most
+		/// `MaxFaucetClaimsPerBlock` claims at a time.
+		#[pallet::weight((T::WeightInfo::faucet_claim(), Pays::No))]
+		pub fn faucet_claim(origin: OriginFor<T>, dest: T::AccountId) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::ensure_can_claim(&dest)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let amount = T::FaucetAmount::get();
+			Self::do_mint(T::FaucetAsset::get(), &dest, amount)?;
+			<LastFaucetClaim<T, I>>::insert(&dest, now);
+			<FaucetClaims<T, I>>::mutate(|(block, count)| {
+				if *block != now {
+					*block = now;
+					*count = 0;
+				}
+				*count = count.saturating_add(1);
+			});
+
+			Self::deposit_event(Event::FaucetClaimed { who: dest, amount });
+
+			Ok(())
+		}
+
+		/// Take a snapshot of every balance and `TotalSupply`, to be queried later through
+		/// `balance_of_at` and `total_supply_at`.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::snapshot())]
+		pub fn snapshot(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_snapshot()?;
+
+			Ok(())
+		}
+
+		/// Set the oracle value to `value`.
+		///
+		/// Normally sent by the offchain worker. The sender must be one of `OracleAuthorities`.
+		/// Fails with `OracleDisabled` unless `OracleEnabled` is set, and with `OracleTooEarly`
+		/// until `OracleInterval` blocks have passed since the last value.
+		#[pallet::weight(T::WeightInfo::submit_oracle_value())]
+		pub fn submit_oracle_value(origin: OriginFor<T>, value: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::OracleEnabled::get(), Error::<T, I>::OracleDisabled);
+			ensure!(Self::is_oracle_authority(&who), Error::<T, I>::NotOracleAuthority);
+
+			Self::store_oracle_value(Some(who), value)
+		}
+
+		/// Set the oracle value to the one in `payload`, which `payload.public` signed with
+		/// `signature`.
+		///
+		/// This is an unsigned transaction sent by the offchain worker. `validate_unsigned`
+		/// checks the signature and only lets the first payload of each interval into the pool.
+		/// `payload.public` must be the key of one of `OracleAuthorities`.
+		#[pallet::weight((T::WeightInfo::submit_oracle_value_unsigned(), Pays::No))]
+		pub fn submit_oracle_value_unsigned(
+			origin: OriginFor<T>,
+			payload: OraclePayload<T::Public, T::BlockNumber>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			ensure!(T::OracleEnabled::get(), Error::<T, I>::OracleDisabled);
+			ensure!(
+				Self::is_oracle_authority(&payload.public.clone().into_account()),
+				Error::<T, I>::NotOracleAuthority
+			);
+
+			Self::store_oracle_value(None, payload.value)
+		}
+
+		/// Replace `OracleAuthorities`, the accounts allowed to submit oracle values, with
+		/// `authorities`.
+		///
+		/// The origin must be `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_oracle_authorities(authorities.len() as u32))]
+		pub fn set_oracle_authorities(
+			origin: OriginFor<T>,
+			authorities: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let bounded = BoundedVec::<_, T::MaxOracleAuthorities>::try_from(authorities.clone())
+				.map_err(|_| Error::<T, I>::TooManyOracleAuthorities)?;
+
+			<OracleAuthorities<T, I>>::put(bounded);
+
+			Self::deposit_event(Event::OracleAuthoritiesSet { authorities });
 
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn transfers(origin: OriginFor<T>, to: T::AccountId, amount: u128) -> DispatchResult {
 			Ok(())
 		}
 
 		/// An example dispatchable that may throw a custom error.
-		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		#[pallet::weight(T::WeightInfo::cause_error())]
 		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
 			let _who = ensure_signed(origin)?;
 
 			// Read a value from storage.
-			match <Something<T>>::get() {
+			match <Something<T, I>>::get() {
 				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
+				None => return Err(Error::<T, I>::NoneValue.into()),
 				Some(old) => {
 					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+					let new = old.checked_add(1).ok_or(Error::<T, I>::StorageOverflow)?;
 					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
+					<Something<T, I>>::put(new);
 					Ok(())
 				},
 			}
 		}
 	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::faucet_claim { dest } => {
+					Self::ensure_can_claim(dest).map_err(|error| match error {
+						Error::<T, I>::FaucetCapReached => InvalidTransaction::ExhaustsResources,
+						Error::<T, I>::FaucetCooldown => InvalidTransaction::Stale,
+						_ => InvalidTransaction::Call,
+					})?;
+
+					// Claims are tagged with the instance, so that claims for the same account
+					// from two instances of the pallet do not replace each other.
+					//
+					// Every claim also takes one of `MaxFaucetClaimsPerBlock` slots, picked by
+					// `dest`, so that the pool never holds more claims than a block can include
+					// however many fresh accounts they are made for.
+					let slots = T::MaxFaucetClaimsPerBlock::get().max(1) as u64;
+					let slot =
+						u64::from_le_bytes(dest.using_encoded(sp_io::hashing::twox_64)) % slots;
+					ValidTransaction::with_tag_prefix("TemplateFaucet")
+						.and_provides((<Self as PalletInfoAccess>::name(), dest))
+						.and_provides((<Self as PalletInfoAccess>::name(), "slot", slot))
+						.longevity(FAUCET_CLAIM_LONGEVITY)
+						.propagate(true)
+						.build()
+				},
+				Call::submit_oracle_value_unsigned { payload, _signature: signature } =>
+					Self::validate_oracle_payload(payload, signature),
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Deposit `event` with the hash of each account it names as a topic, once per account
+		/// even if it is named several times.
+		pub(super) fn deposit_event(event: Event<T, I>) {
+			let mut topics = Vec::new();
+			for who in event.accounts() {
+				let topic = T::Hashing::hash_of(who);
+				if !topics.contains(&topic) {
+					topics.push(topic);
+				}
+			}
+			let event = <T as Config<I>>::Event::from(event);
+			<frame_system::Pallet<T>>::deposit_event_indexed(&topics, event.into());
+		}
+
+		/// The asset the single-asset trait implementations operate on.
+		pub fn default_asset() -> T::AssetId {
+			T::AssetId::default()
+		}
+
+		/// The display information of `asset`, or `None` if the asset does not exist.
+		pub fn asset_metadata(asset: T::AssetId) -> Option<AssetMetadata<Vec<u8>>> {
+			<Assets<T, I>>::contains_key(asset).then(|| {
+				let metadata = Self::metadata(asset);
+				AssetMetadata {
+					name: metadata.name.into_inner(),
+					symbol: metadata.symbol.into_inner(),
+					decimals: metadata.decimals,
+				}
+			})
+		}
+
+		/// Ensure `dest` may claim from the faucet in the current block, and that `FaucetAsset`
+		/// exists and is not paused.
+		pub(crate) fn ensure_can_claim(dest: &T::AccountId) -> Result<(), Error<T, I>> {
+			ensure!(T::FaucetEnabled::get(), Error::<T, I>::FaucetDisabled);
+			let asset = T::FaucetAsset::get();
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			if let Some(last) = <LastFaucetClaim<T, I>>::get(dest) {
+				ensure!(
+					now >= last.saturating_add(T::FaucetCooldown::get()),
+					Error::<T, I>::FaucetCooldown
+				);
+			}
+
+			let (block, count) = <FaucetClaims<T, I>>::get();
+			ensure!(
+				block != now || count < T::MaxFaucetClaimsPerBlock::get(),
+				Error::<T, I>::FaucetCapReached
+			);
+
+			Ok(())
+		}
+
+		/// Bound a raw name and symbol to `StringLimit`.
+		pub(crate) fn bounded_metadata(
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> Result<AssetMetadata<BoundedVec<u8, T::StringLimit>>, Error<T, I>> {
+			Ok(AssetMetadata {
+				name: name.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				symbol: symbol.try_into().map_err(|_| Error::<T, I>::BadMetadata)?,
+				decimals,
+			})
+		}
+
+		/// Ensure `origin` is either `O` or signed by the admin of `asset`.
+		pub(crate) fn ensure_admin_or<O: EnsureOrigin<T::Origin>>(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+		) -> DispatchResult {
+			let details = <Assets<T, I>>::get(asset).ok_or(Error::<T, I>::UnknownAsset)?;
+			if let Err(origin) = O::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(who == details.admin, Error::<T, I>::NoPermission);
+			}
+
+			Ok(())
+		}
+
+		/// The free balance of `who` in `asset`.
+		pub fn balance_of(asset: T::AssetId, who: &T::AccountId) -> u128 {
+			Self::balances(asset, who).free
+		}
+
+		/// Unreserve `amount` of the deposit `who` holds for its numbers.
+		fn release_number_deposit(who: &T::AccountId, amount: NativeBalanceOf<T, I>) {
+			T::NativeCurrency::unreserve(who, amount);
+			<NumberDeposits<T, I>>::mutate_exists(who, |held| {
+				let remaining = held.unwrap_or_default().saturating_sub(amount);
+				*held = (!remaining.is_zero()).then(|| remaining);
+			});
+		}
+
+		/// The number `who` stored most recently, if any.
+		pub fn latest_number(who: &T::AccountId) -> Option<u32> {
+			Self::numbers(who).last().map(|entry| entry.value)
+		}
+
+		/// Up to `limit` of the numbers stored by `who`, oldest first, skipping the first `start`.
+		pub fn numbers_page(
+			who: &T::AccountId,
+			start: u32,
+			limit: u32,
+		) -> Vec<NumberEntry<T::BlockNumber>> {
+			Self::numbers(who)
+				.into_iter()
+				.skip(start as usize)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// The reserved balance of `who` in `asset`, including all of its named holds.
+		pub fn reserved_balance_of(asset: T::AssetId, who: &T::AccountId) -> u128 {
+			Self::balances(asset, who).reserved
+		}
+
+		/// The amount of `asset` that `spender` may still move on behalf of `owner`.
+		pub fn allowance(asset: T::AssetId, owner: &T::AccountId, spender: &T::AccountId) -> u128 {
+			<Allowances<T, I>>::get((asset, owner, spender)).unwrap_or_default()
+		}
+
+		/// The bytes `owner` signs to permit `spender` to move `amount` of its `asset` tokens.
+		///
+		/// The payload is bound to this chain by its genesis hash, to this instance of the pallet
+		/// by its name and to a single use by `nonce`.
+		pub fn permit_payload(
+			asset: T::AssetId,
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			amount: u128,
+			nonce: u64,
+			deadline: T::BlockNumber,
+		) -> Vec<u8> {
+			let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+			let instance = <Self as PalletInfoAccess>::name();
+			(PERMIT_DOMAIN, genesis_hash, instance, asset, owner, spender, amount, nonce, deadline)
+				.encode()
+		}
+
+		/// Set the allowance of `spender` over `owner`'s `asset` tokens and emit `Approval`.
+		///
+		/// A zero allowance removes the entry instead of storing it.
+		pub fn do_approve(
+			asset: T::AssetId,
+			owner: &T::AccountId,
+			spender: &T::AccountId,
+			amount: u128,
+		) {
+			if amount == 0 {
+				<Allowances<T, I>>::remove((asset, owner, spender));
+			} else {
+				<Allowances<T, I>>::insert((asset, owner, spender), amount);
+			}
+
+			Self::deposit_event(Event::Approval {
+				asset,
+				owner: owner.clone(),
+				spender: spender.clone(),
+				amount,
+			});
+		}
+
+		/// Credit `amount` new `asset` tokens to `to`, growing `TotalSupply`, and emit `Minted`.
+		pub fn do_mint(asset: T::AssetId, to: &T::AccountId, amount: u128) -> DispatchResult {
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			let supply =
+				Self::total_supply(asset).checked_add(amount).ok_or(Error::<T, I>::Overflow)?;
+			Self::ensure_can_receive(asset, to, amount)?;
+			let mut account = <Balances<T, I>>::get(asset, to);
+			account.free = account.free.checked_add(amount).ok_or(Error::<T, I>::Overflow)?;
+
+			Self::checkpoint_supply(asset);
+			<TotalSupply<T, I>>::insert(asset, supply);
+			Self::write_account(asset, to, account);
+
+			Self::deposit_event(Event::Minted { asset, to: to.clone(), amount });
+
+			Ok(())
+		}
+
+		/// Destroy `amount` of `from`'s free `asset` tokens, shrinking `TotalSupply`, and emit
+		/// `Burned`. A balance left below `MinBalance` is removed as dust, as long as
+		/// `ensure_can_debit` allows it.
+		pub fn do_burn(asset: T::AssetId, from: &T::AccountId, amount: u128) -> DispatchResult {
+			let mut account = <Balances<T, I>>::get(asset, from);
+			account.free =
+				account.free.checked_sub(amount).ok_or(Error::<T, I>::InsufficientBalance)?;
+			Self::ensure_can_debit(asset, from, &account)?;
+
+			Self::checkpoint_supply(asset);
+			<TotalSupply<T, I>>::mutate(asset, |supply| *supply = supply.saturating_sub(amount));
+			Self::write_account(asset, from, account);
+
+			Self::deposit_event(Event::Burned { asset, from: from.clone(), amount });
+
+			Ok(())
+		}
+
+		/// Check that every asset's `TotalSupply` equals the sum of its free and reserved
+		/// `Balances`, that no balances are empty or held in unknown assets, and that `Holds` fit
+		/// into the reserved balances they belong to.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			for asset in <Assets<T, I>>::iter_keys() {
+				let sum = <Balances<T, I>>::iter_prefix_values(asset)
+					.try_fold(0u128, |acc, account| acc.checked_add(account.total()))
+					.ok_or("sum of Balances overflows u128")?;
+				ensure!(
+					sum == Self::total_supply(asset),
+					"TotalSupply does not match the sum of Balances"
+				);
+			}
+			ensure!(
+				<Balances<T, I>>::iter_keys().all(|(asset, _)| <Assets<T, I>>::contains_key(asset)),
+				"Balances are held in an unknown asset"
+			);
+			ensure!(
+				<Balances<T, I>>::iter_values().all(|account| account.total() > 0),
+				"Empty balances are stored"
+			);
+			for (asset, who, account) in <Balances<T, I>>::iter() {
+				let held = <Holds<T, I>>::iter_prefix_values((asset, &who))
+					.try_fold(0u128, |acc, held| acc.checked_add(held))
+					.ok_or("sum of Holds overflows u128")?;
+				ensure!(held <= account.reserved, "Holds exceed the reserved balance");
+			}
+			ensure!(
+				<Holds<T, I>>::iter_keys()
+					.all(|(asset, who, _)| <Balances<T, I>>::contains_key(asset, who)),
+				"Holds are kept for a removed balance"
+			);
+
+			Ok(())
+		}
+
+		/// Ensure crediting `amount` of `asset` to `who` would not start its balance below
+		/// `MinBalance`.
+		pub fn ensure_can_receive(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			amount: u128,
+		) -> Result<(), Error<T, I>> {
+			ensure!(
+				amount >= T::MinBalance::get() || <Balances<T, I>>::contains_key(asset, who),
+				Error::<T, I>::BelowMinimum
+			);
+
+			Ok(())
+		}
+
+		/// Ensure withdrawing `amount` of `asset` leaves `who` with at least `MinBalance`.
+		pub fn ensure_keeps_alive(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			amount: u128,
+		) -> Result<(), Error<T, I>> {
+			let remaining = <Balances<T, I>>::get(asset, who).total().saturating_sub(amount);
+			ensure!(remaining >= T::MinBalance::get(), Error::<T, I>::KeepAlive);
+
+			Ok(())
+		}
+
+		/// Ensure `account`, the `asset` balance of `who` after a debit, can be written.
+		///
+		/// A balance left below `MinBalance` is removed, which `ensure_can_reap` must allow.
+		pub fn ensure_can_debit(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			account: &AccountData,
+		) -> Result<(), Error<T, I>> {
+			if account.total() >= T::MinBalance::get().max(1) ||
+				!<Balances<T, I>>::contains_key(asset, who)
+			{
+				return Ok(())
+			}
+
+			Self::ensure_can_reap(asset, who)
+		}
+
+		/// Ensure the `asset` balance of `who` can be removed: none of it is locked, and `who` has
+		/// no consumers that need the provider reference of the balance.
+		pub fn ensure_can_reap(asset: T::AssetId, who: &T::AccountId) -> Result<(), Error<T, I>> {
+			ensure!(Self::locked_balance(asset, who) == 0, Error::<T, I>::BalanceLocked);
+			ensure!(<frame_system::Pallet<T>>::can_dec_provider(who), Error::<T, I>::WouldDie);
+
+			Ok(())
+		}
+
+		/// Store `account` as the `asset` balance of `who`, keeping its provider reference in
+		/// step with the entry.
+		///
+		/// A new entry adds a provider reference to `who`. An entry whose total drops below
+		/// `MinBalance` is removed along with its reference and named holds, and its remainder is
+		/// taken out of `TotalSupply` as dust and handed to `OnDust`. Callers that debit a
+		/// balance check `ensure_can_debit` first, so that the removal cannot fail.
+		///
+		/// The previous total is first recorded for the current snapshot, if it has not changed
+		/// since that snapshot was taken.
+		pub(crate) fn write_account(asset: T::AssetId, who: &T::AccountId, account: AccountData) {
+			Self::checkpoint_balance(asset, who);
+			let existed = <Balances<T, I>>::contains_key(asset, who);
+			let dust = account.total();
+			// Empty balances are never stored, even without a minimum.
+			if dust >= T::MinBalance::get().max(1) {
+				if !existed {
+					<frame_system::Pallet<T>>::inc_providers(who);
+				}
+				<Balances<T, I>>::insert(asset, who, account);
+				return
+			}
+
+			if existed {
+				if let Err(error) = Self::ensure_can_reap(asset, who) {
+					log::error!(
+						target: "runtime::template",
+						"{:?} balance of {:?} is below MinBalance but cannot be removed: {:?}",
+						asset,
+						who,
+						error,
+					);
+					<Balances<T, I>>::insert(asset, who, account);
+					return
+				}
+				// Cannot fail: `ensure_can_reap` checked that the provider can be removed.
+				let _ = <frame_system::Pallet<T>>::dec_providers(who);
+				<Balances<T, I>>::remove(asset, who);
+				let _ = <Holds<T, I>>::remove_prefix((asset, who), None);
+			}
+
+			if dust > 0 {
+				Self::checkpoint_supply(asset);
+				<TotalSupply<T, I>>::mutate(asset, |supply| *supply = supply.saturating_sub(dust));
+				T::OnDust::on_dust(asset, who, dust);
+				Self::deposit_event(Event::DustLost { asset, who: who.clone(), amount: dust });
+			}
+		}
+
+		/// Take a new balance snapshot and emit `SnapshotTaken`, returning its id.
+		pub fn do_snapshot() -> Result<u32, DispatchError> {
+			let id = Self::current_snapshot().checked_add(1).ok_or(Error::<T, I>::Overflow)?;
+			<CurrentSnapshot<T, I>>::put(id);
+			<SnapshotBlocks<T, I>>::insert(id, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::SnapshotTaken { id });
+
+			Ok(id)
+		}
+
+		/// The total `asset` balance of `who` as of snapshot `id`, or `None` if that snapshot has
+		/// not been taken.
+		pub fn balance_of_at(asset: T::AssetId, who: &T::AccountId, id: u32) -> Option<u128> {
+			Self::value_at(
+				id,
+				|id| <BalanceSnapshots<T, I>>::get((asset, who, id)),
+				|| Self::balances(asset, who).total(),
+			)
+		}
+
+		/// The `TotalSupply` of `asset` as of snapshot `id`, or `None` if that snapshot has not
+		/// been taken.
+		pub fn total_supply_at(asset: T::AssetId, id: u32) -> Option<u128> {
+			Self::value_at(
+				id,
+				|id| <SupplySnapshots<T, I>>::get(asset, id),
+				|| Self::total_supply(asset),
+			)
+		}
+
+		/// The value as of snapshot `id`: the first one recorded for `id` or a later snapshot,
+		/// since nothing changed it in between, or else the `current` one.
+		///
+		/// Reads one entry per snapshot taken since `id` in the worst case.
+		fn value_at(
+			id: u32,
+			recorded: impl Fn(u32) -> Option<u128>,
+			current: impl FnOnce() -> u128,
+		) -> Option<u128> {
+			let latest = Self::current_snapshot();
+			if id == 0 || id > latest {
+				return None
+			}
+
+			Some((id..=latest).find_map(recorded).unwrap_or_else(current))
+		}
+
+		/// Record the total `asset` balance of `who` for the current snapshot, unless it already
+		/// changed since that snapshot was taken. Must be called before the balance changes.
+		fn checkpoint_balance(asset: T::AssetId, who: &T::AccountId) {
+			let id = Self::current_snapshot();
+			if id > 0 && !<BalanceSnapshots<T, I>>::contains_key((asset, who, id)) {
+				<BalanceSnapshots<T, I>>::insert(
+					(asset, who, id),
+					Self::balances(asset, who).total(),
+				);
+			}
+		}
+
+		/// Record the `TotalSupply` of `asset` for the current snapshot, unless it already
+		/// changed since that snapshot was taken. Must be called before the supply changes.
+		pub(crate) fn checkpoint_supply(asset: T::AssetId) {
+			let id = Self::current_snapshot();
+			if id > 0 && !<SupplySnapshots<T, I>>::contains_key(asset, id) {
+				<SupplySnapshots<T, I>>::insert(asset, id, Self::total_supply(asset));
+			}
+		}
+
+		/// Ensure `who` may currently send `asset`.
+		pub fn ensure_can_send(asset: T::AssetId, who: &T::AccountId) -> Result<(), Error<T, I>> {
+			ensure!(!Self::is_paused(asset), Error::<T, I>::Paused);
+			ensure!(!Self::is_frozen(asset, who), Error::<T, I>::Frozen);
+
+			Ok(())
+		}
+
+		/// The part of `who`'s free `asset` balance that cannot be withdrawn: the largest of its
+		/// vesting lock and its `Locks`.
+		pub fn locked_balance(asset: T::AssetId, who: &T::AccountId) -> u128 {
+			Self::locks(asset, who)
+				.iter()
+				.fold(Self::vesting_locks(asset, who), |acc, lock| acc.max(lock.amount))
+		}
+
+		/// Ensure a free `asset` balance of `new_free` for `who` keeps its locked balance covered.
+		pub fn ensure_unlocked(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			new_free: u128,
+		) -> Result<(), Error<T, I>> {
+			ensure!(new_free >= Self::locked_balance(asset, who), Error::<T, I>::BalanceLocked);
+
+			Ok(())
+		}
+
+		/// Recompute the vesting lock of `who` in `asset` at the current block, dropping completed
+		/// schedules, and emit `VestingUpdated` or `VestingCompleted`.
+		pub fn do_vest(asset: T::AssetId, who: &T::AccountId) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut schedules = Self::vesting(asset, who).unwrap_or_default();
+			schedules.retain(|schedule| schedule.locked_at(now) > 0);
+			let unvested = schedules
+				.iter()
+				.fold(0u128, |acc, schedule| acc.saturating_add(schedule.locked_at(now)));
+
+			if schedules.is_empty() {
+				if <Vesting<T, I>>::take(asset, who).is_some() {
+					<frame_system::Pallet<T>>::dec_consumers(who);
+				}
+				<VestingLocks<T, I>>::remove(asset, who);
+				Self::deposit_event(Event::VestingCompleted { asset, who: who.clone() });
+			} else {
+				<Vesting<T, I>>::insert(asset, who, schedules);
+				<VestingLocks<T, I>>::insert(asset, who, unvested);
+				Self::deposit_event(Event::VestingUpdated { asset, who: who.clone(), unvested });
+			}
+		}
+
+		/// Debit `amount` of `asset` from the free balance of `from` and credit it to the free
+		/// balance of `to`, emitting `Transfer`.
+		///
+		/// Both balances are checked before either is written, so a failed transfer leaves
+		/// storage untouched. Fails if `asset` is paused, `from` is frozen, the transfer would
+		/// spend tokens that are still vesting or it would start the balance of `to` below
+		/// `MinBalance`. A balance of `from` left below `MinBalance` is removed as dust.
+		pub fn do_transfer(
+			asset: T::AssetId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			Self::ensure_can_send(asset, from)?;
+			let mut from_account = <Balances<T, I>>::get(asset, from);
+			from_account.free = from_account
+				.free
+				.checked_sub(amount)
+				.ok_or(Error::<T, I>::InsufficientBalance)?;
+			Self::ensure_unlocked(asset, from, from_account.free)?;
+
+			if from != to {
+				Self::ensure_can_debit(asset, from, &from_account)?;
+				Self::ensure_can_receive(asset, to, amount)?;
+				let mut to_account = <Balances<T, I>>::get(asset, to);
+				to_account.free =
+					to_account.free.checked_add(amount).ok_or(Error::<T, I>::Overflow)?;
+				Self::write_account(asset, from, from_account);
+				Self::write_account(asset, to, to_account);
+			}
+
+			Self::deposit_event(Event::Transfer {
+				asset,
+				from: from.clone(),
+				to: to.clone(),
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Move `amount` of `who`'s free `asset` balance to its reserved balance and emit
+		/// `Reserved`.
+		///
+		/// Fails if `asset` is paused, `who` is frozen or the reserve would dip into tokens that
+		/// are still vesting, so locked funds cannot be moved out through a reserve.
+		pub fn do_reserve(asset: T::AssetId, who: &T::AccountId, amount: u128) -> DispatchResult {
+			ensure!(<Assets<T, I>>::contains_key(asset), Error::<T, I>::UnknownAsset);
+			if amount == 0 {
+				return Ok(())
+			}
+			Self::ensure_can_send(asset, who)?;
+
+			<Balances<T, I>>::try_mutate(asset, who, |account| -> DispatchResult {
+				account.free =
+					account.free.checked_sub(amount).ok_or(Error::<T, I>::InsufficientBalance)?;
+				Self::ensure_unlocked(asset, who, account.free)?;
+				account.reserved =
+					account.reserved.checked_add(amount).ok_or(Error::<T, I>::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Reserved { asset, who: who.clone(), amount });
+
+			Ok(())
+		}
+
+		/// Move up to `amount` of `who`'s reserved `asset` balance back to its free balance and
+		/// emit `Unreserved`.
+		///
+		/// Returns the part of `amount` that was not reserved and so could not be moved.
+		pub fn do_unreserve(asset: T::AssetId, who: &T::AccountId, amount: u128) -> u128 {
+			if amount == 0 || Self::reserved_balance_of(asset, who) == 0 {
+				return amount
+			}
+
+			let actual = <Balances<T, I>>::mutate(asset, who, |account| {
+				let actual = account.reserved.min(amount);
+				account.reserved -= actual;
+				// Cannot overflow: `free + reserved` is bounded by `TotalSupply`.
+				account.free = account.free.saturating_add(actual);
+				actual
+			});
+
+			Self::deposit_event(Event::Unreserved { asset, who: who.clone(), amount: actual });
+
+			amount - actual
+		}
+
+		/// Move up to `amount` of `slashed`'s reserved `asset` balance to the free or reserved
+		/// balance of `beneficiary`, as given by `status`, and emit `ReserveRepatriated`.
+		///
+		/// Returns the part of `amount` that was not reserved and so could not be moved.
+		pub fn do_repatriate_reserved(
+			asset: T::AssetId,
+			slashed: &T::AccountId,
+			beneficiary: &T::AccountId,
+			amount: u128,
+			status: BalanceStatus,
+		) -> Result<u128, DispatchError> {
+			if amount == 0 {
+				return Ok(0)
+			}
+			if slashed == beneficiary {
+				return match status {
+					BalanceStatus::Free => Ok(Self::do_unreserve(asset, slashed, amount)),
+					BalanceStatus::Reserved =>
+						Ok(amount.saturating_sub(Self::reserved_balance_of(asset, slashed))),
+				}
+			}
+
+			let mut from = <Balances<T, I>>::get(asset, slashed);
+			let mut to = <Balances<T, I>>::get(asset, beneficiary);
+			let actual = from.reserved.min(amount);
+			Self::ensure_can_receive(asset, beneficiary, actual)?;
+			match status {
+				BalanceStatus::Free =>
+					to.free = to.free.checked_add(actual).ok_or(Error::<T, I>::Overflow)?,
+				BalanceStatus::Reserved =>
+					to.reserved = to.reserved.checked_add(actual).ok_or(Error::<T, I>::Overflow)?,
+			}
+			from.reserved -= actual;
+			Self::ensure_can_debit(asset, slashed, &from)?;
+			Self::write_account(asset, slashed, from);
+			Self::write_account(asset, beneficiary, to);
+
+			Self::deposit_event(Event::ReserveRepatriated {
+				asset,
+				from: slashed.clone(),
+				to: beneficiary.clone(),
+				amount: actual,
+				destination_status: status,
+			});
+
+			Ok(amount - actual)
+		}
+
+		/// Reserve `amount` of `who`'s free `asset` balance under the hold `reason`.
+		pub fn do_reserve_named(
+			asset: T::AssetId,
+			reason: &T::HoldReason,
+			who: &T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			Self::do_reserve(asset, who, amount)?;
+			<Holds<T, I>>::mutate((asset, who, reason), |held| *held = held.saturating_add(amount));
+
+			Ok(())
+		}
+
+		/// Unreserve up to `amount` of the `asset` tokens `who` holds under `reason`.
+		///
+		/// Returns the part of `amount` that was not held and so could not be unreserved.
+		pub fn do_unreserve_named(
+			asset: T::AssetId,
+			reason: &T::HoldReason,
+			who: &T::AccountId,
+			amount: u128,
+		) -> u128 {
+			let held = Self::holds((asset, who, reason));
+			let to_release = held.min(amount);
+			let released = to_release - Self::do_unreserve(asset, who, to_release);
+			Self::set_hold(asset, reason, who, held - released);
+
+			amount - released
+		}
+
+		/// Move up to `amount` of the `asset` tokens `slashed` holds under `reason` to
+		/// `beneficiary`, as `do_repatriate_reserved` does. Funds moved to the reserved balance of
+		/// `beneficiary` are held there under the same `reason`.
+		///
+		/// Returns the part of `amount` that was not held and so could not be moved.
+		pub fn do_repatriate_reserved_named(
+			asset: T::AssetId,
+			reason: &T::HoldReason,
+			slashed: &T::AccountId,
+			beneficiary: &T::AccountId,
+			amount: u128,
+			status: BalanceStatus,
+		) -> Result<u128, DispatchError> {
+			let held = Self::holds((asset, slashed, reason));
+			if slashed == beneficiary {
+				return match status {
+					BalanceStatus::Free =>
+						Ok(Self::do_unreserve_named(asset, reason, slashed, amount)),
+					BalanceStatus::Reserved => Ok(amount.saturating_sub(held)),
+				}
+			}
+
+			let to_move = held.min(amount);
+			let moved = to_move -
+				Self::do_repatriate_reserved(asset, slashed, beneficiary, to_move, status)?;
+			Self::set_hold(asset, reason, slashed, held - moved);
+			if status == BalanceStatus::Reserved {
+				<Holds<T, I>>::mutate((asset, beneficiary, reason), |held| {
+					*held = held.saturating_add(moved)
+				});
+			}
+
+			Ok(amount - moved)
+		}
+
+		/// Withdraw a transaction fee of `amount` from `who`'s free `asset` balance. The fee stays
+		/// part of `TotalSupply` until `do_settle_fee` pays it out.
+		///
+		/// Fails with `KeepAlive` rather than leave `who` below `MinBalance`, so that the refund
+		/// has a balance to go back to.
+		pub(crate) fn do_withdraw_fee(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			amount: u128,
+		) -> DispatchResult {
+			Self::ensure_can_send(asset, who)?;
+			let mut account = <Balances<T, I>>::get(asset, who);
+			account.free =
+				account.free.checked_sub(amount).ok_or(Error::<T, I>::InsufficientBalance)?;
+			Self::ensure_unlocked(asset, who, account.free)?;
+			Self::ensure_keeps_alive(asset, who, amount)?;
+			Self::write_account(asset, who, account);
+
+			Ok(())
+		}
+
+		/// Settle a fee of `paid` withdrawn from `who`: refund what exceeds `actual`, credit
+		/// `actual` to `FeeCollector` and emit `FeePaid`.
+		///
+		/// A fee too small to start the balance of `FeeCollector` is lost as dust.
+		pub(crate) fn do_settle_fee(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			paid: u128,
+			actual: u128,
+		) {
+			// Cannot overflow: both amounts are part of `TotalSupply` and out of any balance.
+			let mut account = <Balances<T, I>>::get(asset, who);
+			account.free = account.free.saturating_add(paid.saturating_sub(actual));
+			Self::write_account(asset, who, account);
+			let collector = T::FeeCollector::get();
+			let mut account = <Balances<T, I>>::get(asset, &collector);
+			account.free = account.free.saturating_add(actual);
+			Self::write_account(asset, &collector, account);
+
+			Self::deposit_event(Event::FeePaid { asset, who: who.clone(), actual_fee: actual });
+		}
+
+		/// Store the amount `who` holds under `reason`, removing the entry when it is zero.
+		pub(crate) fn set_hold(
+			asset: T::AssetId,
+			reason: &T::HoldReason,
+			who: &T::AccountId,
+			amount: u128,
+		) {
+			if amount == 0 {
+				<Holds<T, I>>::remove((asset, who, reason));
+			} else {
+				<Holds<T, I>>::insert((asset, who, reason), amount);
+			}
+		}
+
+		/// Shrink the holds of `who` in `asset` after `amount` left its reserve of `reserved`
+		/// through an unnamed path, so that they still fit into what is reserved. The part of the
+		/// reserve not held under any reason is taken first.
+		pub(crate) fn release_holds(
+			asset: T::AssetId,
+			who: &T::AccountId,
+			reserved: u128,
+			amount: u128,
+		) {
+			let holds = <Holds<T, I>>::iter_prefix((asset, who)).collect::<Vec<_>>();
+			let held = holds.iter().fold(0u128, |acc, (_, held)| acc.saturating_add(*held));
+			let mut excess = amount.saturating_sub(reserved.saturating_sub(held));
+			for (reason, held) in holds {
+				if excess == 0 {
+					break
+				}
+				let released = held.min(excess);
+				Self::set_hold(asset, &reason, who, held - released);
+				excess -= released;
+			}
+		}
+	}
 }
@@ -0,0 +1,389 @@
+//! Storage migrations for the template pallet.
+//!
+//! Every step migrates from one `StorageVersion` to the next and does nothing when the on-chain
+//! version is any other, so all of them can stay listed in a runtime's `Executive`. Add a new
+//! `vN` module and append it to `Migrations` whenever the storage layout changes.
+//!
+//! The steps migrate the default instance of the pallet, the only one that existed before the
+//! pallet became instantiable. Other instances start out at the current version when their
+//! genesis is built, but one added to a running chain has no genesis: list
+//! `InitializeInstance` for it, so that it starts out at the current version too.
+
+use super::*;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// All storage migrations of the pallet, in order. `Owner` owns and administers the asset the
+/// single-asset layout is moved into.
+pub type Migrations<T, Owner> = (
+	v1::MigrateToMultiAsset<T, Owner>,
+	v2::MigrateToAccountData<T>,
+	v3::MigrateToNumberHistory<T>,
+	v4::MigrateToAccountLifecycle<T>,
+);
+
+/// Put the current storage version for instance `I` if it has neither a storage version nor any
+/// storage, as when it is added to a chain that is already running.
+///
+/// An instance with storage but no version is left alone, since its layout is unknown.
+pub struct InitializeInstance<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for InitializeInstance<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		let name = <Pallet<T, I> as PalletInfoAccess>::name();
+		if Pallet::<T, I>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let prefix = sp_io::hashing::twox_128(name.as_bytes());
+		if sp_io::storage::next_key(&prefix).map_or(false, |key| key.starts_with(&prefix)) {
+			log::warn!(
+				target: "runtime::template",
+				"{} has storage but no storage version, leaving it alone",
+				name,
+			);
+			return T::DbWeight::get().reads(2)
+		}
+
+		let version = Pallet::<T, I>::current_storage_version();
+		version.put::<Pallet<T, I>>();
+
+		log::info!(target: "runtime::template", "initialized {} at {:?}", name, version);
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Pallet::<T, I>::on_chain_storage_version() == Pallet::<T, I>::current_storage_version(),
+			"instance is not at the current storage version"
+		);
+
+		Ok(())
+	}
+}
+
+pub(crate) mod v0 {
+	//! The single-asset token layout.
+
+	use super::*;
+	use frame_support::{pallet_prelude::*, storage_alias};
+
+	#[storage_alias]
+	pub(crate) type Balances<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Never part of a released single-asset layout, but written by runtimes that tracked the
+	/// supply before the migration to multiple assets existed.
+	#[storage_alias]
+	pub(crate) type TotalSupply<T: Config> = StorageValue<Pallet<T>, u128, ValueQuery>;
+
+	#[storage_alias]
+	pub(crate) type Allowances<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u128,
+		OptionQuery,
+	>;
+}
+
+pub mod v1 {
+	//! Move the single-asset token layout into asset `AssetId::default()`.
+
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade, weights::Weight,
+	};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	/// `Balances` as of v1, before the reserved balance was split out.
+	#[storage_alias]
+	pub(crate) type Balances<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::AssetId,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u128,
+		ValueQuery,
+	>;
+
+	/// Re-key `Balances` and `Allowances` by asset id, moving every existing entry into
+	/// `AssetId::default()` and registering that asset with `Owner` as owner and admin.
+	///
+	/// The single-asset layout kept no supply, so the `TotalSupply` of the new asset is the sum of
+	/// the moved balances. Any old `TotalSupply` value is discarded.
+	///
+	/// The old and new layouts share storage prefixes, so each old map is drained in full before
+	/// any new entry is written.
+	pub struct MigrateToMultiAsset<T, Owner>(PhantomData<(T, Owner)>);
+
+	impl<T: Config, Owner: Get<T::AccountId>> OnRuntimeUpgrade for MigrateToMultiAsset<T, Owner> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(
+					target: "runtime::template",
+					"skipping v1 migration: storage version is not 0",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let asset = Pallet::<T>::default_asset();
+			let balances = v0::Balances::<T>::drain().collect::<Vec<_>>();
+			let allowances = v0::Allowances::<T>::drain().collect::<Vec<_>>();
+			v0::TotalSupply::<T>::kill();
+			let supply = balances
+				.iter()
+				.try_fold(0u128, |acc, (_, balance)| acc.checked_add(*balance))
+				.unwrap_or_else(|| {
+					log::error!(
+						target: "runtime::template",
+						"sum of v0 balances overflows u128, capping TotalSupply",
+					);
+					u128::MAX
+				});
+
+			let owner = Owner::get();
+			crate::Assets::<T>::insert(asset, AssetDetails { owner: owner.clone(), admin: owner });
+			crate::TotalSupply::<T>::insert(asset, supply);
+			for (who, balance) in &balances {
+				Balances::<T>::insert(asset, who, balance);
+			}
+			for (who, spender, amount) in &allowances {
+				crate::Allowances::<T>::insert((asset, who, spender), amount);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			let entries = (balances.len() + allowances.len()) as u64;
+			log::info!(target: "runtime::template", "migrated {} entries to v1", entries);
+			T::DbWeight::get().reads_writes(entries + 2, entries * 2 + 4)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 0, "expected storage version 0");
+
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "expected storage version 1");
+			ensure!(
+				crate::Assets::<T>::contains_key(Pallet::<T>::default_asset()),
+				"default asset was not created"
+			);
+
+			let supply = crate::TotalSupply::<T>::get(Pallet::<T>::default_asset());
+			let sum = Balances::<T>::iter_prefix_values(Pallet::<T>::default_asset())
+				.try_fold(0u128, |acc, balance| acc.checked_add(balance));
+			ensure!(sum == Some(supply), "TotalSupply does not match the sum of Balances");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	//! Split each account's balance into a free and a reserved part.
+
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade, weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// `Numbers` as of v2, holding a single number per account.
+	#[storage_alias]
+	pub(crate) type Numbers<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Turn every `u128` in `Balances` into an `AccountData` whose free balance is the old value
+	/// and whose reserved balance is zero.
+	pub struct MigrateToAccountData<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToAccountData<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::info!(
+					target: "runtime::template",
+					"skipping v2 migration: storage version is not 1",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			crate::Balances::<T>::translate_values::<u128, _>(|free| {
+				translated += 1;
+				Some(AccountData { free, reserved: 0 })
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::template", "migrated {} balances to v2", translated);
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 1, "expected storage version 1");
+
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 2, "expected storage version 2");
+
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
+pub mod v3 {
+	//! Keep a bounded history of numbers per account instead of a single one.
+
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+	use sp_std::{marker::PhantomData, vec};
+
+	/// Turn every `u32` in `Numbers` into a one-entry history. The block each number was stored
+	/// in is not known, so the entry is dated to the block of the upgrade.
+	pub struct MigrateToNumberHistory<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToNumberHistory<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 2 {
+				log::info!(
+					target: "runtime::template",
+					"skipping v3 migration: storage version is not 2",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let block = <frame_system::Pallet<T>>::block_number();
+			let mut translated = 0u64;
+			crate::Numbers::<T>::translate_values::<u32, _>(|value| {
+				translated += 1;
+				BoundedVec::try_from(vec![NumberEntry { value, block }]).ok()
+			});
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::template", "migrated {} numbers to v3", translated);
+			T::DbWeight::get().reads_writes(translated + 2, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 2, "expected storage version 2");
+
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 3, "expected storage version 3");
+
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
+
+pub mod v4 {
+	//! Tie `Balances` entries to `frame_system` provider and consumer references.
+
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	/// Remove empty `Balances` entries and give every account one provider reference per
+	/// remaining entry and one consumer reference per asset it has vesting schedules in.
+	///
+	/// Entries below `MinBalance` are kept; they are removed as dust the next time they change.
+	pub struct MigrateToAccountLifecycle<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToAccountLifecycle<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 3 {
+				log::info!(
+					target: "runtime::template",
+					"skipping v4 migration: storage version is not 3",
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let balances = Balances::<T>::iter().collect::<Vec<_>>();
+			let mut removed = 0u64;
+			for (asset, who, account) in &balances {
+				if account.total() == 0 {
+					Balances::<T>::remove(asset, who);
+					removed += 1;
+				} else {
+					<frame_system::Pallet<T>>::inc_providers(who);
+				}
+			}
+
+			let mut consumers = 0u64;
+			for (_, who) in Vesting::<T>::iter_keys() {
+				if <frame_system::Pallet<T>>::inc_consumers(&who).is_err() {
+					log::warn!(
+						target: "runtime::template",
+						"vesting account {:?} has no provider to consume",
+						who,
+					);
+				}
+				consumers += 1;
+			}
+
+			StorageVersion::new(4).put::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::template",
+				"migrated {} balances and {} vesting accounts to v4, removing {} empty balances",
+				balances.len(),
+				consumers,
+				removed,
+			);
+			let entries = balances.len() as u64;
+			T::DbWeight::get()
+				.reads_writes(2 * entries + 2 * consumers + 1, entries + consumers + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 3, "expected storage version 3");
+
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			ensure!(StorageVersion::get::<Pallet<T>>() == 4, "expected storage version 4");
+			ensure!(
+				Balances::<T>::iter_keys()
+					.all(|(_, who)| <frame_system::Pallet<T>>::providers(&who) > 0),
+				"an account holding tokens has no provider"
+			);
+
+			Pallet::<T>::do_try_state()
+		}
+	}
+}
@@ -1,10 +1,16 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use frame_support::{
+	instances::Instance1,
+	parameter_types,
+	traits::{ConstBool, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8},
+	weights::IdentityFee,
+};
 use frame_system as system;
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, TestXt, UintAuthorityId},
+	traits::{BlakeTwo256, Extrinsic as ExtrinsicT, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -18,7 +24,14 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
+		NativeBalances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
+		TemplateModule: pallet_template::{
+			Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned
+		},
+		RewardToken: pallet_template::<Instance1>::{
+			Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned
+		},
 	}
 );
 
@@ -40,7 +53,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u128>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -49,11 +62,341 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u128;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_transaction_payment::Config for Test {
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<NativeBalances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<u128>;
+	type LengthToFee = IdentityFee<u128>;
+	type FeeMultiplierUpdate = ();
+}
+
+parameter_types! {
+	pub static FaucetEnabled: bool = true;
+	pub static SignedOracleSubmissions: bool = false;
+	pub static MinBalance: u128 = 1;
+	pub static Dust: Vec<(u32, u64, u128)> = vec![];
+	pub static SnapshotPeriod: u64 = 0;
+	pub const OracleUrl: &'static str = ORACLE_URL;
+}
+
+/// The endpoint the offchain worker fetches oracle values from.
+pub const ORACLE_URL: &str = "http://localhost:8000/oracle";
+
+/// The account credited with fees paid in template tokens.
+pub const FEE_COLLECTOR: u64 = 100;
+
+/// The native deposit reserved for each stored number.
+pub const NUMBER_DEPOSIT: u128 = 5;
+
 impl pallet_template::Config for Test {
 	type Event = Event;
+	type WeightInfo = ();
+	type AssetId = u32;
+	type HoldReason = [u8; 8];
+	type MaxLocks = ConstU32<4>;
+	type CreateOrigin = EnsureRoot<u64>;
+	type MintOrigin = EnsureRoot<u64>;
+	type BurnOrigin = EnsureRoot<u64>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type FreezeOrigin = EnsureRoot<u64>;
+	type StringLimit = ConstU32<8>;
+	type MinBalance = MinBalance;
+	type OnDust = RecordDust;
+	type FaucetEnabled = FaucetEnabled;
+	type FaucetAsset = ConstU32<0>;
+	type FaucetAmount = ConstU128<100>;
+	type FaucetCooldown = ConstU64<10>;
+	type MaxFaucetClaimsPerBlock = ConstU32<2>;
+	type MinVestedTransfer = ConstU128<10>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type MaxNumbers = ConstU32<3>;
+	type NativeCurrency = NativeBalances;
+	type NumberDeposit = ConstU128<NUMBER_DEPOSIT>;
+	type MaxBatchSize = ConstU32<4>;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	type FeeCollector = ConstU64<FEE_COLLECTOR>;
+	type OracleEnabled = ConstBool<true>;
+	type AuthorityId = TestOracleId;
+	type OracleUrl = OracleUrl;
+	type OracleInterval = ConstU64<5>;
+	type OracleUnsignedPriority = ConstU64<100>;
+	type SignedOracleSubmissions = SignedOracleSubmissions;
+	type MaxOracleAuthorities = ConstU32<4>;
+	type SnapshotPeriod = SnapshotPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PermitSigner;
+}
+
+/// A second token, configured like `TemplateModule` but with its own storage and events.
+impl pallet_template::Config<Instance1> for Test {
+	type Event = Event;
+	type WeightInfo = ();
+	type AssetId = u32;
+	type HoldReason = [u8; 8];
+	type MaxLocks = ConstU32<4>;
+	type CreateOrigin = EnsureRoot<u64>;
+	type MintOrigin = EnsureRoot<u64>;
+	type BurnOrigin = EnsureRoot<u64>;
+	type AdminOrigin = EnsureRoot<u64>;
+	type FreezeOrigin = EnsureRoot<u64>;
+	type StringLimit = ConstU32<8>;
+	type MinBalance = ConstU128<1>;
+	type OnDust = ();
+	type FaucetEnabled = FaucetEnabled;
+	type FaucetAsset = ConstU32<0>;
+	type FaucetAmount = ConstU128<100>;
+	type FaucetCooldown = ConstU64<10>;
+	type MaxFaucetClaimsPerBlock = ConstU32<2>;
+	type MinVestedTransfer = ConstU128<10>;
+	type MaxVestingSchedules = ConstU32<3>;
+	type MaxNumbers = ConstU32<3>;
+	type NativeCurrency = NativeBalances;
+	type NumberDeposit = ConstU128<NUMBER_DEPOSIT>;
+	type MaxBatchSize = ConstU32<4>;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	type FeeCollector = ConstU64<FEE_COLLECTOR>;
+	// `TemplateModule` runs the oracle.
+	type OracleEnabled = ConstBool<false>;
+	type AuthorityId = TestOracleId;
+	type OracleUrl = OracleUrl;
+	type OracleInterval = ConstU64<5>;
+	type OracleUnsignedPriority = ConstU64<100>;
+	type SignedOracleSubmissions = SignedOracleSubmissions;
+	type MaxOracleAuthorities = ConstU32<4>;
+	type SnapshotPeriod = ConstU64<0>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = PermitSigner;
+}
+
+/// Records every dust removal in `Dust`.
+pub struct RecordDust;
+
+impl pallet_template::OnDust<u32, u64> for RecordDust {
+	fn on_dust(asset: u32, who: &u64, amount: u128) {
+		let mut dust = Dust::get();
+		dust.push((asset, *who, amount));
+		Dust::set(dust);
+	}
+}
+
+/// The transactions submitted by the offchain worker.
+pub type Extrinsic = TestXt<Call, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		_public: UintAuthorityId,
+		_account: u64,
+		nonce: u64,
+	) -> Option<(Call, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce, ())))
+	}
+}
+
+/// Signs oracle submissions with the keys set through `UintAuthorityId::set_all_keys`.
+pub struct TestOracleId;
+
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestOracleId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+/// Signs benchmark permits as account `1`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct PermitSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::BenchmarkHelper<u64, TestSignature> for PermitSigner {
+	fn signer() -> u64 {
+		1
+	}
+
+	fn sign(signer: &u64, message: &[u8]) -> TestSignature {
+		TestSignature(*signer, message.to_vec())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
 }
+
+mod instances {
+	//! Checks that `TemplateModule` and `RewardToken` do not share state.
+
+	use super::*;
+	use frame_support::{assert_noop, assert_ok, traits::GenesisBuild};
+
+	#[test]
+	fn instances_are_configured_separately_at_genesis() {
+		let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		pallet_template::GenesisConfig::<Test> {
+			assets: vec![(0, 10, 10)],
+			metadata: vec![(0, b"Template".to_vec(), b"TMPL".to_vec(), 12)],
+			balances: vec![(0, 1, 100)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+		pallet_template::GenesisConfig::<Test, Instance1> {
+			assets: vec![(0, 20, 20)],
+			metadata: vec![(0, b"Reward".to_vec(), b"RWRD".to_vec(), 6)],
+			balances: vec![(0, 2, 30)],
+			..Default::default()
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			assert_eq!(TemplateModule::asset(0).map(|details| details.owner), Some(10));
+			assert_eq!(RewardToken::asset(0).map(|details| details.owner), Some(20));
+			assert_eq!(TemplateModule::metadata(0).symbol.into_inner(), b"TMPL".to_vec());
+			assert_eq!(RewardToken::metadata(0).symbol.into_inner(), b"RWRD".to_vec());
+			assert_eq!(TemplateModule::metadata(0).decimals, 12);
+			assert_eq!(RewardToken::metadata(0).decimals, 6);
+			assert_eq!(TemplateModule::balance_of(0, &1), 100);
+			assert_eq!(TemplateModule::balance_of(0, &2), 0);
+			assert_eq!(RewardToken::balance_of(0, &1), 0);
+			assert_eq!(RewardToken::balance_of(0, &2), 30);
+			assert_eq!(TemplateModule::total_supply(0), 100);
+			assert_eq!(RewardToken::total_supply(0), 30);
+			assert_eq!(TemplateModule::do_try_state(), Ok(()));
+			assert_eq!(RewardToken::do_try_state(), Ok(()));
+		});
+	}
+
+	#[test]
+	fn instances_keep_separate_assets_and_balances() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(TemplateModule::create_asset(Origin::root(), 0, 10, 11));
+			assert_ok!(TemplateModule::mint(Origin::root(), 0, 1, 100));
+			assert_noop!(
+				RewardToken::mint(Origin::root(), 0, 1, 30),
+				pallet_template::Error::<Test, Instance1>::UnknownAsset
+			);
+
+			assert_ok!(RewardToken::create_asset(Origin::root(), 0, 20, 20));
+			assert_ok!(RewardToken::mint(Origin::root(), 0, 1, 30));
+			assert_ok!(RewardToken::transfers(Origin::signed(1), 0, 2, 10));
+			System::assert_last_event(Event::RewardToken(pallet_template::Event::Transfer {
+				asset: 0,
+				from: 1,
+				to: 2,
+				amount: 10,
+			}));
+
+			assert_eq!(TemplateModule::asset(0).map(|details| details.owner), Some(10));
+			assert_eq!(RewardToken::asset(0).map(|details| details.owner), Some(20));
+			assert_eq!(TemplateModule::balance_of(0, &1), 100);
+			assert_eq!(TemplateModule::balance_of(0, &2), 0);
+			assert_eq!(RewardToken::balance_of(0, &1), 20);
+			assert_eq!(RewardToken::balance_of(0, &2), 10);
+			assert_eq!(TemplateModule::total_supply(0), 100);
+			assert_eq!(RewardToken::total_supply(0), 30);
+			// Each instance holds its own provider reference.
+			assert_eq!(System::providers(&1), 2);
+			assert_eq!(TemplateModule::do_try_state(), Ok(()));
+			assert_eq!(RewardToken::do_try_state(), Ok(()));
+		});
+	}
+
+	#[test]
+	fn instances_keep_separate_permissions_and_snapshots() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(TemplateModule::create_asset(Origin::root(), 0, 10, 10));
+			assert_ok!(RewardToken::create_asset(Origin::root(), 0, 10, 10));
+			assert_ok!(TemplateModule::mint(Origin::root(), 0, 1, 100));
+			assert_ok!(RewardToken::mint(Origin::root(), 0, 1, 100));
+
+			assert_ok!(RewardToken::freeze(Origin::root(), 0, 1));
+			assert_ok!(TemplateModule::transfers(Origin::signed(1), 0, 2, 10));
+			assert_noop!(
+				RewardToken::transfers(Origin::signed(1), 0, 2, 10),
+				pallet_template::Error::<Test, Instance1>::Frozen
+			);
+
+			assert_ok!(TemplateModule::approve(Origin::signed(1), 0, 3, 50));
+			assert_eq!(TemplateModule::allowance(0, &1, &3), 50);
+			assert_eq!(RewardToken::allowance(0, &1, &3), 0);
+			// A permit signed for one instance cannot be used on the other.
+			assert_ne!(
+				TemplateModule::permit_payload(0, &1, &3, 50, 0, 5),
+				RewardToken::permit_payload(0, &1, &3, 50, 0, 5)
+			);
+
+			assert_ok!(TemplateModule::snapshot(Origin::root()));
+			assert_eq!(TemplateModule::current_snapshot(), 1);
+			assert_eq!(RewardToken::current_snapshot(), 0);
+			assert_eq!(RewardToken::balance_of_at(0, &1, 1), None);
+		});
+	}
+
+	#[test]
+	fn only_the_default_instance_runs_the_oracle() {
+		use frame_support::{
+			pallet_prelude::{InvalidTransaction, TransactionSource},
+			unsigned::ValidateUnsigned,
+		};
+
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![7]));
+			assert_ok!(RewardToken::set_oracle_authorities(Origin::root(), vec![7]));
+
+			assert_noop!(
+				RewardToken::submit_oracle_value(Origin::signed(7), 42),
+				pallet_template::Error::<Test, Instance1>::OracleDisabled
+			);
+			let payload = pallet_template::OraclePayload {
+				public: UintAuthorityId(7),
+				block_number: 1,
+				value: 42,
+			};
+			let call = pallet_template::Call::<Test, Instance1>::submit_oracle_value_unsigned {
+				payload: payload.clone(),
+				_signature: TestSignature(7, codec::Encode::encode(&payload)),
+			};
+			assert_eq!(
+				RewardToken::validate_unsigned(TransactionSource::External, &call),
+				Err(InvalidTransaction::Call.into())
+			);
+
+			assert_ok!(TemplateModule::submit_oracle_value(Origin::signed(7), 42));
+			assert_eq!(TemplateModule::oracle_value(), Some(42));
+			assert_eq!(RewardToken::oracle_value(), None);
+		});
+	}
+}
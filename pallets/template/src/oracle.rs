@@ -0,0 +1,213 @@
+//! An oracle fed by the pallet's offchain worker.
+//!
+//! Once `OracleInterval` blocks have passed since the last value, the offchain worker of every
+//! node holding a `KEY_TYPE` key fetches a new value from `OracleUrl`. The endpoint must answer a
+//! `GET` request with a decimal `u32` as its body. The value is then sent back in a transaction
+//! signed by that key or, unless `SignedOracleSubmissions` is set, in an unsigned transaction
+//! carrying a payload signed by it.
+//!
+//! Keys are added to a node's keystore with the `author_insertKey` RPC, using key type `tmpl`.
+//! Only the accounts in `OracleAuthorities`, set by `AdminOrigin`, may submit values: a node's
+//! offchain worker signs with the first of its keys whose account is one of them, and stays idle
+//! if it holds none.
+//!
+//! Each instance of the pallet with `OracleEnabled` set runs its own oracle, with its own
+//! authorities but the same key type. A runtime should enable it on one instance only, unless it
+//! really wants several independent values.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, traits::PalletInfoAccess, RuntimeDebug};
+use frame_system::offchain::{
+	AppCrypto, SendSignedTransaction, SendUnsignedTransaction, SignedPayload, Signer, SigningTypes,
+};
+use scale_info::TypeInfo;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	offchain::{http, Duration},
+	traits::{IdentifyAccount, SaturatedConversion, Saturating},
+	RuntimeAppPublic,
+};
+use sp_std::vec::Vec;
+
+/// The key type of the keys that sign oracle submissions.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"tmpl");
+
+/// How long the offchain worker waits for the oracle endpoint, in milliseconds.
+const FETCH_TIMEOUT_MS: u64 = 2_000;
+
+/// Oracle keys, backed by sr25519.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// Signs oracle submissions for runtimes whose accounts are `MultiSigner`s.
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// An oracle value signed by `public`, submitted in an unsigned transaction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OraclePayload<Public, BlockNumber> {
+	/// The key that signed the payload.
+	pub public: Public,
+	/// The block whose offchain worker fetched `value`.
+	pub block_number: BlockNumber,
+	/// The fetched value.
+	pub value: u32,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for OraclePayload<T::Public, T::BlockNumber> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// Why the offchain worker could not submit an oracle value.
+#[derive(RuntimeDebug)]
+pub enum OracleError {
+	/// The request failed or timed out.
+	Http(http::Error),
+	/// The endpoint answered with a status other than 200.
+	UnexpectedStatus(u16),
+	/// The response body is not a decimal `u32`.
+	BadBody,
+	/// The transaction pool did not accept the transaction.
+	Rejected,
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Fetch a value and submit it, if this node holds the key of an oracle authority.
+	pub(crate) fn submit_oracle_value_offchain(now: T::BlockNumber) -> Result<(), OracleError> {
+		let keys = Self::local_oracle_keys();
+		if keys.is_empty() {
+			return Ok(())
+		}
+
+		let signer = Signer::<T, T::AuthorityId>::any_account().with_filter(keys);
+
+		let value = Self::fetch_oracle_value()?;
+		let result = if T::SignedOracleSubmissions::get() {
+			signer
+				.send_signed_transaction(|_| Call::submit_oracle_value { value })
+				.map(|(_, result)| result)
+		} else {
+			signer
+				.send_unsigned_transaction(
+					|account| OraclePayload {
+						public: account.public.clone(),
+						block_number: now,
+						value,
+					},
+					|payload, signature| Call::submit_oracle_value_unsigned {
+						payload,
+						_signature: signature,
+					},
+				)
+				.map(|(_, result)| result)
+		};
+
+		match result {
+			Some(Ok(())) => Ok(()),
+			_ => Err(OracleError::Rejected),
+		}
+	}
+
+	/// The oracle keys in this node's keystore whose accounts are in `OracleAuthorities`.
+	fn local_oracle_keys() -> Vec<T::Public> {
+		type OracleCrypto<T, I> = <T as Config<I>>::AuthorityId;
+
+		<OracleCrypto<T, I> as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic::all()
+			.into_iter()
+			.map(|key| {
+				<OracleCrypto<T, I> as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key)
+					.into()
+			})
+			.filter(|public: &T::Public| Self::is_oracle_authority(&public.clone().into_account()))
+			.collect()
+	}
+
+	/// Whether `who` is one of `OracleAuthorities`.
+	pub(crate) fn is_oracle_authority(who: &T::AccountId) -> bool {
+		Self::oracle_authorities().contains(who)
+	}
+
+	/// Fetch the current value from `OracleUrl`.
+	pub fn fetch_oracle_value() -> Result<u32, OracleError> {
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+		let pending = http::Request::get(T::OracleUrl::get())
+			.deadline(deadline)
+			.send()
+			.map_err(|_| OracleError::Http(http::Error::IoError))?;
+		let response = pending
+			.try_wait(deadline)
+			.map_err(|_| OracleError::Http(http::Error::DeadlineReached))?
+			.map_err(OracleError::Http)?;
+		if response.code != 200 {
+			return Err(OracleError::UnexpectedStatus(response.code))
+		}
+
+		let body = response.body().collect::<Vec<u8>>();
+		sp_std::str::from_utf8(&body)
+			.ok()
+			.and_then(|body| body.trim().parse().ok())
+			.ok_or(OracleError::BadBody)
+	}
+
+	/// Admit an unsigned oracle submission if `signature` is `payload.public`'s, that key
+	/// belongs to one of `OracleAuthorities` and it is the first submission of the current
+	/// interval.
+	pub(crate) fn validate_oracle_payload(
+		payload: &OraclePayload<T::Public, T::BlockNumber>,
+		signature: &T::Signature,
+	) -> TransactionValidity {
+		if !T::OracleEnabled::get() {
+			return InvalidTransaction::Call.into()
+		}
+		if !Self::is_oracle_authority(&payload.public.clone().into_account()) {
+			return InvalidTransaction::BadProof.into()
+		}
+		if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+			return InvalidTransaction::BadProof.into()
+		}
+
+		let next = Self::next_oracle_at();
+		if payload.block_number < next {
+			return InvalidTransaction::Stale.into()
+		}
+		if payload.block_number > <frame_system::Pallet<T>>::block_number() {
+			return InvalidTransaction::Future.into()
+		}
+
+		ValidTransaction::with_tag_prefix("TemplateOracle")
+			.priority(T::OracleUnsignedPriority::get())
+			.and_provides((<Self as PalletInfoAccess>::name(), next))
+			.longevity(T::OracleInterval::get().saturated_into::<u64>().max(1))
+			.propagate(true)
+			.build()
+	}
+
+	/// Record `value`, submitted by `who` or in an unsigned transaction, and start the next
+	/// interval.
+	pub(crate) fn store_oracle_value(who: Option<T::AccountId>, value: u32) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(now >= Self::next_oracle_at(), Error::<T, I>::OracleTooEarly);
+
+		<OracleValue<T, I>>::put(value);
+		<NextOracleAt<T, I>>::put(now.saturating_add(T::OracleInterval::get()));
+
+		Self::deposit_event(Event::OracleValueSubmitted { who, value });
+
+		Ok(())
+	}
+}
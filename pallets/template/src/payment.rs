@@ -0,0 +1,191 @@
+//! A signed extension that lets transactions pay their fee in a template asset instead of the
+//! native token.
+//!
+//! The fee is computed by `pallet_transaction_payment` as usual, then converted at the asset's
+//! `FeeRates` entry and withdrawn before dispatch. After dispatch, the part of the fee not used by
+//! the actual weight is refunded and the rest is credited to `FeeCollector`. Transactions that do
+//! not name an asset are charged in the native token by `ChargeTransactionPayment`.
+//!
+//! Fees are paid in assets of the pallet's default instance.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, SignedExtension,
+	},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+};
+
+type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// How the fee of a transaction was withdrawn before dispatch.
+pub enum InitialPayment<T: Config + pallet_transaction_payment::Config>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// The fee was withdrawn in the native token.
+	Native(<ChargeTransactionPayment<T> as SignedExtension>::Pre),
+	/// `amount` of `asset` was withdrawn from `who`, converted at `rate`.
+	Template {
+		who: T::AccountId,
+		asset: T::AssetId,
+		amount: u128,
+		rate: FixedU128,
+		tip: BalanceOf<T>,
+	},
+}
+
+/// Charge the transaction fee, plus `tip`, in `asset` if one is given, or in the native token
+/// otherwise.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTemplateFee<T: Config + pallet_transaction_payment::Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	asset: Option<T::AssetId>,
+}
+
+impl<T: Config + pallet_transaction_payment::Config> ChargeTemplateFee<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Pay `tip` on top of the fee, in `asset` or in the native token.
+	pub fn from(tip: BalanceOf<T>, asset: Option<T::AssetId>) -> Self {
+		Self { tip, asset }
+	}
+
+	/// Withdraw the fee in `asset` from `who`, returning the amount withdrawn and the rate it was
+	/// converted at.
+	///
+	/// Nothing is withdrawn for a feeless call without a tip, so that it stays valid whatever the
+	/// state of `who`'s balance.
+	fn withdraw_fee(
+		&self,
+		asset: T::AssetId,
+		who: &T::AccountId,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<(BalanceOf<T>, u128, FixedU128), TransactionValidityError> {
+		let rate = Pallet::<T>::fee_rate(asset).ok_or(InvalidTransaction::Payment)?;
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let amount = rate.saturating_mul_int(fee.saturated_into::<u128>());
+
+		if amount > 0 {
+			Pallet::<T>::do_withdraw_fee(asset, who, amount)
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
+
+		Ok((fee, amount, rate))
+	}
+}
+
+impl<T: Config + pallet_transaction_payment::Config> sp_std::fmt::Debug for ChargeTemplateFee<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeTemplateFee<{:?}, {:?}>", self.tip, self.asset)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + pallet_transaction_payment::Config> SignedExtension for ChargeTemplateFee<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeTemplateFee";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = InitialPayment<T>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.asset {
+			None => ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len),
+			Some(asset) => {
+				let (fee, _, _) = self.withdraw_fee(asset, who, info, len)?;
+				let priority =
+					ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+
+				Ok(ValidTransaction { priority, ..Default::default() })
+			},
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match self.asset {
+			None => ChargeTransactionPayment::<T>::from(self.tip)
+				.pre_dispatch(who, call, info, len)
+				.map(InitialPayment::Native),
+			Some(asset) => {
+				let (_, amount, rate) = self.withdraw_fee(asset, who, info, len)?;
+
+				Ok(InitialPayment::Template {
+					who: who.clone(),
+					asset,
+					amount,
+					rate,
+					tip: self.tip,
+				})
+			},
+		}
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			Some(InitialPayment::Native(pre)) => ChargeTransactionPayment::<T>::post_dispatch(
+				Some(pre),
+				info,
+				post_info,
+				len,
+				result,
+			),
+			Some(InitialPayment::Template { amount: 0, .. }) => Ok(()),
+			Some(InitialPayment::Template { who, asset, amount, rate, tip }) => {
+				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
+					len as u32, info, post_info, tip,
+				);
+				let actual =
+					rate.saturating_mul_int(actual_fee.saturated_into::<u128>()).min(amount);
+				Pallet::<T>::do_settle_fee(asset, &who, amount, actual);
+
+				Ok(())
+			},
+			None => Ok(()),
+		}
+	}
+}
@@ -1,5 +1,23 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, AccountData, AssetDetails, AssetMetadata, Balances, Error, Locks, NumberEntry,
+	OraclePayload, VestingInfo,
+};
+use frame_support::{assert_noop, assert_ok, traits::GenesisBuild};
+use sp_runtime::{testing::TestSignature, traits::BadOrigin};
+
+mod migrations;
+
+const ASSET: u32 = 0;
+
+/// Give `who` enough native balance to pay for a few number deposits.
+fn fund(who: u64) {
+	let _ = <NativeBalances as frame_support::traits::Currency<u64>>::deposit_creating(&who, 100);
+}
+
+/// Create `ASSET`, owned by account 10 and administered by account 11.
+fn create_asset() {
+	assert_ok!(TemplateModule::create_asset(Origin::root(), ASSET, 10, 11));
+}
 
 #[test]
 fn it_works_for_default_value() {
@@ -11,6 +29,71 @@ fn it_works_for_default_value() {
 	});
 }
 
+#[test]
+fn events_are_indexed_by_the_accounts_they_name() {
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 3, 10));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10));
+		assert_ok!(TemplateModule::pause(Origin::root(), ASSET));
+
+		let topics = |who: u64| System::event_topics(&BlakeTwo256::hash_of(&who));
+		assert_eq!(topics(1).len(), 3);
+		assert_eq!(topics(2).len(), 1);
+		assert_eq!(topics(3).len(), 1);
+
+		let events = System::events();
+		let (transfer, paused) = (&events[events.len() - 2], &events[events.len() - 1]);
+		assert_eq!(
+			transfer.event,
+			Event::TemplateModule(crate::Event::Transfer {
+				asset: ASSET,
+				from: 1,
+				to: 2,
+				amount: 10
+			})
+		);
+		assert_eq!(transfer.topics, vec![BlakeTwo256::hash_of(&1u64), BlakeTwo256::hash_of(&2u64)]);
+		assert!(paused.topics.is_empty());
+	});
+}
+
+#[test]
+fn events_naming_an_account_twice_are_indexed_once() {
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::create_asset(Origin::root(), ASSET, 1, 1));
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 1, 10));
+
+		let events = System::events();
+		assert_eq!(
+			events.last().unwrap().event,
+			Event::TemplateModule(crate::Event::Transfer {
+				asset: ASSET,
+				from: 1,
+				to: 1,
+				amount: 10
+			})
+		);
+		assert_eq!(events.last().unwrap().topics, vec![BlakeTwo256::hash_of(&1u64)]);
+		// `AssetCreated` names 1 as both owner and admin.
+		let created = events
+			.iter()
+			.find(|record| {
+				matches!(record.event, Event::TemplateModule(crate::Event::AssetCreated { .. }))
+			})
+			.unwrap();
+		assert_eq!(created.topics, vec![BlakeTwo256::hash_of(&1u64)]);
+	});
+}
+
 #[test]
 fn correct_error_for_none_value() {
 	new_test_ext().execute_with(|| {
@@ -18,3 +101,2046 @@ fn correct_error_for_none_value() {
 		assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
 	});
 }
+
+#[test]
+fn put_number_appends_until_full() {
+	new_test_ext().execute_with(|| {
+		fund(1);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 7));
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 8));
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 9));
+		System::assert_last_event(Event::TemplateModule(crate::Event::NumberStored {
+			who: 1,
+			value: 9,
+		}));
+
+		assert_eq!(
+			TemplateModule::numbers(1).into_inner(),
+			vec![
+				NumberEntry { value: 7, block: 1 },
+				NumberEntry { value: 8, block: 2 },
+				NumberEntry { value: 9, block: 2 },
+			]
+		);
+		assert_eq!(TemplateModule::latest_number(&1), Some(9));
+
+		assert_noop!(
+			TemplateModule::put_number(Origin::signed(1), 10),
+			Error::<Test>::TooManyNumbers
+		);
+	});
+}
+
+#[test]
+fn remove_number_removes_by_index() {
+	new_test_ext().execute_with(|| {
+		fund(1);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 7));
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 8));
+
+		assert_noop!(
+			TemplateModule::remove_number(Origin::signed(1), 2),
+			Error::<Test>::NumberNotFound
+		);
+
+		assert_ok!(TemplateModule::remove_number(Origin::signed(1), 0));
+		System::assert_last_event(Event::TemplateModule(crate::Event::NumberRemoved {
+			who: 1,
+			index: 0,
+			value: 7,
+		}));
+		assert_eq!(
+			TemplateModule::numbers(1).into_inner(),
+			vec![NumberEntry { value: 8, block: 1 }]
+		);
+
+		assert_ok!(TemplateModule::remove_number(Origin::signed(1), 0));
+		assert!(!crate::Numbers::<Test>::contains_key(1));
+		assert_eq!(TemplateModule::latest_number(&1), None);
+	});
+}
+
+#[test]
+fn clear_numbers_removes_history() {
+	new_test_ext().execute_with(|| {
+		fund(1);
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 7));
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 8));
+
+		assert_ok!(TemplateModule::clear_numbers(Origin::signed(1)));
+		System::assert_last_event(Event::TemplateModule(crate::Event::NumbersCleared {
+			who: 1,
+			count: 2,
+		}));
+		assert!(!crate::Numbers::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn numbers_page_paginates_history() {
+	new_test_ext().execute_with(|| {
+		fund(1);
+		for number in 0..3 {
+			assert_ok!(TemplateModule::put_number(Origin::signed(1), number));
+		}
+
+		let values = |start, limit| {
+			TemplateModule::numbers_page(&1, start, limit)
+				.into_iter()
+				.map(|entry| entry.value)
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(values(0, 2), vec![0, 1]);
+		assert_eq!(values(2, 2), vec![2]);
+		assert_eq!(values(3, 2), Vec::<u32>::new());
+	});
+}
+
+#[test]
+fn numbers_reserve_and_return_deposits() {
+	new_test_ext().execute_with(|| {
+		fund(1);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 7));
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 8));
+		assert_eq!(NativeBalances::reserved_balance(1), 2 * NUMBER_DEPOSIT);
+		assert_eq!(TemplateModule::number_deposit(1), 2 * NUMBER_DEPOSIT);
+
+		assert_ok!(TemplateModule::remove_number(Origin::signed(1), 1));
+		assert_eq!(NativeBalances::reserved_balance(1), NUMBER_DEPOSIT);
+
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 9));
+		assert_ok!(TemplateModule::clear_numbers(Origin::signed(1)));
+		assert_eq!(NativeBalances::reserved_balance(1), 0);
+		assert_eq!(NativeBalances::free_balance(1), 100);
+		assert!(!crate::NumberDeposits::<Test>::contains_key(1));
+
+		// Account 2 cannot pay the deposit.
+		assert!(TemplateModule::put_number(Origin::signed(2), 7).is_err());
+		assert!(!crate::Numbers::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn remove_number_fails_when_nothing_is_stored() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(TemplateModule::remove_number(Origin::signed(1), 0), Error::<Test>::NoneValue);
+	});
+}
+
+#[test]
+fn reap_numbers_only_removes_numbers_of_killed_accounts() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		fund(1);
+		assert_ok!(TemplateModule::put_number(Origin::signed(1), 7));
+
+		assert_noop!(
+			TemplateModule::reap_numbers(Origin::signed(2), 1),
+			Error::<Test>::AccountStillAlive
+		);
+
+		// Slashing the deposit and the free balance kills the account.
+		let _ = NativeBalances::slash_reserved(&1, NUMBER_DEPOSIT);
+		let _ = <NativeBalances as frame_support::traits::Currency<u64>>::slash(&1, 100);
+		assert!(!System::account_exists(&1));
+
+		assert_ok!(TemplateModule::reap_numbers(Origin::signed(2), 1));
+		System::assert_last_event(Event::TemplateModule(crate::Event::NumbersReaped {
+			who: 1,
+			count: 1,
+		}));
+		assert!(!crate::Numbers::<Test>::contains_key(1));
+		assert!(!crate::NumberDeposits::<Test>::contains_key(1));
+
+		assert_noop!(TemplateModule::reap_numbers(Origin::root(), 1), Error::<Test>::NoneValue);
+	});
+}
+
+#[test]
+fn transfers_moves_balance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 40));
+
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 60);
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 40);
+		System::assert_last_event(Event::TemplateModule(crate::Event::Transfer {
+			asset: ASSET,
+			from: 1,
+			to: 2,
+			amount: 40,
+		}));
+	});
+}
+
+#[test]
+fn transfers_fails_on_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 10));
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 11),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn transfers_fails_on_recipient_overflow() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 10));
+		// Bypass `TotalSupply` so the recipient can sit at the top of the range.
+		Balances::<Test>::insert(ASSET, 2, AccountData { free: u128::MAX, reserved: 0 });
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 1),
+			Error::<Test>::Overflow
+		);
+	});
+}
+
+#[test]
+fn transfers_to_self_is_a_no_op() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 10));
+
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 1, 10));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 10);
+		System::assert_last_event(Event::TemplateModule(crate::Event::Transfer {
+			asset: ASSET,
+			from: 1,
+			to: 1,
+			amount: 10,
+		}));
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 1, 11),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn approve_sets_and_revokes_allowance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 50));
+		assert_eq!(TemplateModule::allowances((ASSET, 1, 2)), Some(50));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Approval {
+			asset: ASSET,
+			owner: 1,
+			spender: 2,
+			amount: 50,
+		}));
+
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 0));
+		assert_eq!(TemplateModule::allowances((ASSET, 1, 2)), None);
+	});
+}
+
+#[test]
+fn increase_and_decrease_allowance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::increase_allowance(Origin::signed(1), ASSET, 2, 30));
+		assert_ok!(TemplateModule::increase_allowance(Origin::signed(1), ASSET, 2, 20));
+		assert_eq!(TemplateModule::allowance(ASSET, &1, &2), 50);
+
+		assert_ok!(TemplateModule::decrease_allowance(Origin::signed(1), ASSET, 2, 45));
+		assert_eq!(TemplateModule::allowance(ASSET, &1, &2), 5);
+
+		assert_noop!(
+			TemplateModule::decrease_allowance(Origin::signed(1), ASSET, 2, 6),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			TemplateModule::increase_allowance(Origin::signed(1), ASSET, 2, u128::MAX),
+			Error::<Test>::Overflow
+		);
+	});
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 60));
+
+		assert_ok!(TemplateModule::transfer_from(Origin::signed(2), ASSET, 1, 3, 40));
+
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 60);
+		assert_eq!(TemplateModule::balance_of(ASSET, &3), 40);
+		assert_eq!(TemplateModule::allowance(ASSET, &1, &2), 20);
+		System::assert_has_event(Event::TemplateModule(crate::Event::Transfer {
+			asset: ASSET,
+			from: 1,
+			to: 3,
+			amount: 40,
+		}));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Approval {
+			asset: ASSET,
+			owner: 1,
+			spender: 2,
+			amount: 20,
+		}));
+	});
+}
+
+#[test]
+fn transfer_from_fails_without_enough_allowance_or_balance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 10));
+
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(2), ASSET, 1, 3, 5),
+			Error::<Test>::InsufficientAllowance
+		);
+
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 50));
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(2), ASSET, 1, 3, 20),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn mint_and_burn_track_total_supply() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 2, 50));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Minted {
+			asset: ASSET,
+			to: 2,
+			amount: 50,
+		}));
+		assert_eq!(TemplateModule::total_supply(ASSET), 150);
+
+		assert_ok!(TemplateModule::burn(Origin::root(), ASSET, 1, 30));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Burned {
+			asset: ASSET,
+			from: 1,
+			amount: 30,
+		}));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 70);
+		assert_eq!(TemplateModule::total_supply(ASSET), 120);
+
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 70));
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn mint_and_burn_require_privileged_origin() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_noop!(TemplateModule::mint(Origin::signed(1), ASSET, 1, 100), BadOrigin);
+
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_noop!(TemplateModule::burn(Origin::signed(1), ASSET, 1, 100), BadOrigin);
+		assert_noop!(TemplateModule::set_balances(Origin::signed(1), ASSET, 1, 200), BadOrigin);
+		assert_noop!(TemplateModule::set_balances(Origin::signed(1), ASSET, 1, 0), BadOrigin);
+	});
+}
+
+#[test]
+fn burn_fails_on_insufficient_balance() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 10));
+
+		assert_noop!(
+			TemplateModule::burn(Origin::root(), ASSET, 1, 11),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn set_balances_adjusts_total_supply() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_ok!(TemplateModule::set_balances(Origin::root(), ASSET, 2, 40));
+		assert_eq!(TemplateModule::total_supply(ASSET), 140);
+		System::assert_last_event(Event::TemplateModule(crate::Event::BalanceSet {
+			asset: ASSET,
+			who: 2,
+			free: 40,
+		}));
+
+		assert_ok!(TemplateModule::set_balances(Origin::root(), ASSET, 1, 10));
+		assert_eq!(TemplateModule::total_supply(ASSET), 50);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn fungible_traits_operate_on_balances() {
+	use frame_support::traits::tokens::{
+		fungible::{Inspect, Mutate, Transfer},
+		DepositConsequence, WithdrawConsequence,
+	};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(<TemplateModule as Mutate<u64>>::mint_into(&1, 100));
+		assert_eq!(<TemplateModule as Inspect<u64>>::balance(&1), 100);
+		assert_eq!(<TemplateModule as Inspect<u64>>::total_issuance(), 100);
+		assert_eq!(<TemplateModule as Inspect<u64>>::reducible_balance(&1, true), 99);
+
+		assert_eq!(<TemplateModule as Transfer<u64>>::transfer(&1, &2, 30, false), Ok(30));
+		assert_eq!(<TemplateModule as Inspect<u64>>::balance(&2), 30);
+
+		assert_eq!(<TemplateModule as Mutate<u64>>::burn_from(&2, 10), Ok(10));
+		assert_eq!(<TemplateModule as Inspect<u64>>::total_issuance(), 90);
+
+		assert_eq!(
+			<TemplateModule as Inspect<u64>>::can_withdraw(&1, 71),
+			WithdrawConsequence::NoFunds
+		);
+		assert_eq!(
+			<TemplateModule as Inspect<u64>>::can_deposit(&1, u128::MAX, true),
+			DepositConsequence::Overflow
+		);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn currency_imbalances_keep_total_supply_in_sync() {
+	use frame_support::traits::{Currency, ExistenceRequirement, Imbalance, WithdrawReasons};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		drop(<TemplateModule as Currency<u64>>::deposit_creating(&1, 100));
+		assert_eq!(TemplateModule::total_supply(ASSET), 100);
+
+		let withdrawn = <TemplateModule as Currency<u64>>::withdraw(
+			&1,
+			40,
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::AllowDeath,
+		)
+		.unwrap();
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 60);
+		drop(withdrawn);
+		assert_eq!(TemplateModule::total_supply(ASSET), 60);
+
+		let (slashed, remaining) = <TemplateModule as Currency<u64>>::slash(&1, 70);
+		assert_eq!(remaining, 10);
+		drop(slashed);
+		assert_eq!(TemplateModule::total_supply(ASSET), 0);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn balances_provide_for_their_accounts() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_eq!(System::providers(&1), 1);
+		assert!(System::account_exists(&1));
+
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 100));
+		assert!(!crate::Balances::<Test>::contains_key(ASSET, 1));
+		assert!(!System::account_exists(&1));
+		assert_eq!(System::providers(&2), 1);
+
+		// Vesting schedules consume the account they lock tokens of.
+		let schedule = VestingInfo { locked: 50, per_block: 50, starting_block: 1 };
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::vested_transfer(Origin::signed(2), ASSET, 3, schedule));
+		assert_eq!(System::consumers(&3), 1);
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::vest(Origin::signed(3), ASSET));
+		assert_eq!(System::consumers(&3), 0);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn balances_below_min_balance_are_removed_as_dust() {
+	use frame_support::traits::{Currency, ExistenceRequirement};
+
+	MinBalance::set(10);
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::mint(Origin::root(), ASSET, 1, 9),
+			Error::<Test>::BelowMinimum
+		);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 5),
+			Error::<Test>::BelowMinimum
+		);
+		assert_noop!(
+			<TemplateModule as Currency<u64>>::transfer(
+				&1,
+				&2,
+				95,
+				ExistenceRequirement::KeepAlive
+			),
+			Error::<Test>::KeepAlive
+		);
+
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 95));
+		System::assert_has_event(Event::TemplateModule(crate::Event::DustLost {
+			asset: ASSET,
+			who: 1,
+			amount: 5,
+		}));
+		assert_eq!(Dust::get(), vec![(ASSET, 1, 5)]);
+		assert!(!crate::Balances::<Test>::contains_key(ASSET, 1));
+		assert!(!System::account_exists(&1));
+		assert_eq!(TemplateModule::total_supply(ASSET), 95);
+
+		// Existing balances may still receive less than the minimum.
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 2, 1));
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 96);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+	MinBalance::set(1);
+}
+
+#[test]
+fn debits_cannot_remove_balances_with_consumers() {
+	use frame_support::traits::{Currency, ExistenceRequirement, Imbalance, WithdrawReasons};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		// The template balance is the only provider of 1, and something else consumes it.
+		assert_ok!(System::inc_consumers(&1));
+
+		assert_noop!(TemplateModule::burn(Origin::root(), ASSET, 1, 100), Error::<Test>::WouldDie);
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 100),
+			Error::<Test>::WouldDie
+		);
+		assert_noop!(
+			<TemplateModule as Currency<u64>>::withdraw(
+				&1,
+				100,
+				WithdrawReasons::all(),
+				ExistenceRequirement::AllowDeath
+			),
+			Error::<Test>::WouldDie
+		);
+
+		// A slash leaves the balance at `MinBalance` instead.
+		let (imbalance, remaining) = <TemplateModule as Currency<u64>>::slash(&1, 100);
+		assert_eq!((imbalance.peek(), remaining), (99, 1));
+		drop(imbalance);
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 1);
+		assert_eq!(System::providers(&1), 1);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+
+		System::dec_consumers(&1);
+		assert_ok!(TemplateModule::burn(Origin::root(), ASSET, 1, 1));
+		assert!(!System::account_exists(&1));
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn debits_cannot_remove_balances_that_are_still_vesting() {
+	use frame_support::traits::{Currency, Imbalance};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		let schedule = VestingInfo { locked: 50, per_block: 1, starting_block: 10 };
+		assert_ok!(TemplateModule::vested_transfer(Origin::signed(1), ASSET, 2, schedule));
+
+		assert_noop!(
+			TemplateModule::burn(Origin::root(), ASSET, 2, 50),
+			Error::<Test>::BalanceLocked
+		);
+		assert_noop!(
+			TemplateModule::set_balances(Origin::root(), ASSET, 2, 0),
+			Error::<Test>::BalanceLocked
+		);
+
+		let (imbalance, remaining) = <TemplateModule as Currency<u64>>::slash(&2, 50);
+		assert_eq!((imbalance.peek(), remaining), (49, 1));
+		drop(imbalance);
+		assert_eq!(crate::Balances::<Test>::get(ASSET, 2), AccountData { free: 1, reserved: 0 });
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn snapshots_record_balances_before_they_change() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 1), None);
+		assert_noop!(TemplateModule::snapshot(Origin::signed(1)), BadOrigin);
+
+		assert_ok!(TemplateModule::snapshot(Origin::root()));
+		System::assert_last_event(Event::TemplateModule(crate::Event::SnapshotTaken { id: 1 }));
+		assert_eq!(TemplateModule::snapshot_block(1), Some(1));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 30));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10));
+		assert_eq!(crate::BalanceSnapshots::<Test>::get((ASSET, 1, 1)), Some(100));
+
+		assert_ok!(TemplateModule::snapshot(Origin::root()));
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 2, 20));
+		assert_ok!(TemplateModule::burn(Origin::root(), ASSET, 1, 10));
+		assert_ok!(TemplateModule::do_reserve(ASSET, &2, 5));
+
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 1), Some(100));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &2, 1), Some(0));
+		assert_eq!(TemplateModule::total_supply_at(ASSET, 1), Some(100));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 2), Some(60));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &2, 2), Some(40));
+		assert_eq!(TemplateModule::total_supply_at(ASSET, 2), Some(100));
+		// Balances that have not changed since are read from the current state, counting
+		// reserved tokens.
+		assert_ok!(TemplateModule::snapshot(Origin::root()));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &2, 3), Some(60));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &3, 1), Some(0));
+		assert_eq!(TemplateModule::total_supply_at(ASSET, 3), Some(110));
+
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 0), None);
+		assert_eq!(TemplateModule::total_supply_at(ASSET, 4), None);
+	});
+}
+
+#[test]
+fn snapshots_are_taken_every_snapshot_period() {
+	use frame_support::traits::OnInitialize;
+
+	SnapshotPeriod::set(5);
+	new_test_ext().execute_with(|| {
+		create_asset();
+		for block in 1..=10 {
+			System::set_block_number(block);
+			TemplateModule::on_initialize(block);
+			if block == 7 {
+				assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+			}
+		}
+
+		assert_eq!(TemplateModule::current_snapshot(), 2);
+		assert_eq!(TemplateModule::snapshot_block(2), Some(10));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 1), Some(0));
+		assert_eq!(TemplateModule::balance_of_at(ASSET, &1, 2), Some(100));
+		assert_eq!(TemplateModule::total_supply_at(ASSET, 1), Some(0));
+	});
+	SnapshotPeriod::set(0);
+}
+
+#[test]
+fn reserve_and_unreserve_move_between_free_and_reserved() {
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_ok!(<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 30));
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 70, reserved: 30 });
+		assert_eq!(<TemplateModule as Currency<u64>>::total_balance(&1), 100);
+		System::assert_last_event(Event::TemplateModule(crate::Event::Reserved {
+			asset: ASSET,
+			who: 1,
+			amount: 30,
+		}));
+
+		// Reserved funds cannot be transferred.
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 71),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 71),
+			Error::<Test>::InsufficientBalance
+		);
+
+		assert_eq!(<TemplateModule as ReservableCurrency<u64>>::unreserve(&1, 50), 20);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 100, reserved: 0 });
+		System::assert_last_event(Event::TemplateModule(crate::Event::Unreserved {
+			asset: ASSET,
+			who: 1,
+			amount: 30,
+		}));
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn reserve_fails_for_frozen_account() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::freeze(Origin::root(), ASSET, 1));
+
+		assert!(!<TemplateModule as ReservableCurrency<u64>>::can_reserve(&1, 10));
+		assert_noop!(
+			<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 10),
+			Error::<Test>::Frozen
+		);
+	});
+}
+
+#[test]
+fn repatriate_reserved_moves_to_free_or_reserved() {
+	use frame_support::traits::{BalanceStatus, ReservableCurrency};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 50));
+
+		assert_eq!(
+			<TemplateModule as ReservableCurrency<u64>>::repatriate_reserved(
+				&1,
+				&2,
+				20,
+				BalanceStatus::Free
+			),
+			Ok(0)
+		);
+		System::assert_last_event(Event::TemplateModule(crate::Event::ReserveRepatriated {
+			asset: ASSET,
+			from: 1,
+			to: 2,
+			amount: 20,
+			destination_status: BalanceStatus::Free,
+		}));
+		assert_eq!(
+			<TemplateModule as ReservableCurrency<u64>>::repatriate_reserved(
+				&1,
+				&3,
+				40,
+				BalanceStatus::Reserved
+			),
+			Ok(10)
+		);
+
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 50, reserved: 0 });
+		assert_eq!(TemplateModule::balances(ASSET, 2), AccountData { free: 20, reserved: 0 });
+		assert_eq!(TemplateModule::balances(ASSET, 3), AccountData { free: 0, reserved: 30 });
+		assert_eq!(TemplateModule::total_supply(ASSET), 100);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn slash_reserved_shrinks_total_supply() {
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 30));
+
+		let (slashed, remaining) =
+			<TemplateModule as ReservableCurrency<u64>>::slash_reserved(&1, 40);
+		assert_eq!(remaining, 10);
+		drop(slashed);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 70, reserved: 0 });
+		assert_eq!(TemplateModule::total_supply(ASSET), 70);
+
+		// `slash` takes from the free balance before the reserved one.
+		assert_ok!(<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 20));
+		let (slashed, remaining) = <TemplateModule as Currency<u64>>::slash(&1, 60);
+		assert_eq!(remaining, 0);
+		drop(slashed);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 0, reserved: 10 });
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn named_holds_are_tracked_per_reason() {
+	use frame_support::traits::{BalanceStatus, NamedReservableCurrency, ReservableCurrency};
+
+	const DEPOSIT: [u8; 8] = *b"deposit_";
+	const ESCROW: [u8; 8] = *b"escrow__";
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_ok!(<TemplateModule as NamedReservableCurrency<u64>>::reserve_named(
+			&DEPOSIT, &1, 30
+		));
+		assert_ok!(<TemplateModule as NamedReservableCurrency<u64>>::reserve_named(
+			&ESCROW, &1, 20
+		));
+		assert_eq!(<TemplateModule as ReservableCurrency<u64>>::reserved_balance(&1), 50);
+		assert_eq!(
+			<TemplateModule as NamedReservableCurrency<u64>>::reserved_balance_named(&DEPOSIT, &1),
+			30
+		);
+
+		// Only the funds held under `DEPOSIT` are released.
+		assert_eq!(
+			<TemplateModule as NamedReservableCurrency<u64>>::unreserve_named(&DEPOSIT, &1, 40),
+			10
+		);
+		assert_eq!(TemplateModule::holds((ASSET, 1, DEPOSIT)), 0);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 80, reserved: 20 });
+
+		// Held funds moved to a reserved balance stay held under the same reason.
+		assert_eq!(
+			<TemplateModule as NamedReservableCurrency<u64>>::repatriate_reserved_named(
+				&ESCROW,
+				&1,
+				&2,
+				15,
+				BalanceStatus::Reserved
+			),
+			Ok(0)
+		);
+		assert_eq!(TemplateModule::holds((ASSET, 1, ESCROW)), 5);
+		assert_eq!(TemplateModule::holds((ASSET, 2, ESCROW)), 15);
+		assert_eq!(TemplateModule::balances(ASSET, 2), AccountData { free: 0, reserved: 15 });
+
+		let (slashed, remaining) =
+			<TemplateModule as NamedReservableCurrency<u64>>::slash_reserved_named(&ESCROW, &2, 20);
+		assert_eq!(remaining, 5);
+		drop(slashed);
+		assert_eq!(TemplateModule::holds((ASSET, 2, ESCROW)), 0);
+		assert_eq!(TemplateModule::total_supply(ASSET), 85);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn slashing_reserved_balance_shrinks_named_holds() {
+	use frame_support::traits::{Currency, NamedReservableCurrency, ReservableCurrency};
+
+	const DEPOSIT: [u8; 8] = *b"deposit_";
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(<TemplateModule as ReservableCurrency<u64>>::reserve(&1, 10));
+		assert_ok!(<TemplateModule as NamedReservableCurrency<u64>>::reserve_named(
+			&DEPOSIT, &1, 30
+		));
+
+		// The unnamed part of the reserve is slashed before the held funds.
+		let (slashed, remaining) =
+			<TemplateModule as ReservableCurrency<u64>>::slash_reserved(&1, 25);
+		assert_eq!(remaining, 0);
+		drop(slashed);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 60, reserved: 15 });
+		assert_eq!(TemplateModule::holds((ASSET, 1, DEPOSIT)), 15);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+
+		let (slashed, remaining) = <TemplateModule as Currency<u64>>::slash(&1, 70);
+		assert_eq!(remaining, 0);
+		drop(slashed);
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 0, reserved: 5 });
+		assert_eq!(TemplateModule::holds((ASSET, 1, DEPOSIT)), 5);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+
+		assert_eq!(<TemplateModule as ReservableCurrency<u64>>::unreserve(&1, 5), 0);
+		assert_eq!(TemplateModule::holds((ASSET, 1, DEPOSIT)), 0);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn locks_restrict_withdrawals() {
+	use frame_support::traits::{
+		tokens::fungible::Inspect, Currency, ExistenceRequirement, LockableCurrency,
+		WithdrawReasons,
+	};
+
+	const STAKING: [u8; 8] = *b"staking_";
+	const VOTING: [u8; 8] = *b"voting__";
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		let consumers = System::consumers(&1);
+
+		<TemplateModule as LockableCurrency<u64>>::set_lock(
+			STAKING,
+			&1,
+			60,
+			WithdrawReasons::all(),
+		);
+		assert_eq!(System::consumers(&1), consumers + 1);
+		assert_eq!(TemplateModule::locked_balance(ASSET, 1), 60);
+		assert_eq!(<TemplateModule as Inspect<u64>>::reducible_balance(&1, false), 40);
+		assert_noop!(
+			<TemplateModule as Currency<u64>>::transfer(
+				&1,
+				&2,
+				50,
+				ExistenceRequirement::AllowDeath
+			),
+			Error::<Test>::BalanceLocked
+		);
+
+		// Extending never shrinks a lock, and overlapping locks do not add up.
+		<TemplateModule as LockableCurrency<u64>>::extend_lock(
+			STAKING,
+			&1,
+			30,
+			WithdrawReasons::all(),
+		);
+		assert_eq!(TemplateModule::locked_balance(ASSET, 1), 60);
+		<TemplateModule as LockableCurrency<u64>>::extend_lock(
+			VOTING,
+			&1,
+			70,
+			WithdrawReasons::all(),
+		);
+		assert_eq!(TemplateModule::locked_balance(ASSET, 1), 70);
+		assert_eq!(System::consumers(&1), consumers + 1);
+
+		<TemplateModule as LockableCurrency<u64>>::remove_lock(VOTING, &1);
+		assert_ok!(<TemplateModule as Currency<u64>>::transfer(
+			&1,
+			&2,
+			40,
+			ExistenceRequirement::AllowDeath
+		));
+		assert_eq!(TemplateModule::balances(ASSET, 1), AccountData { free: 60, reserved: 0 });
+
+		// A locked balance cannot be removed, so slashing leaves `MinBalance` behind.
+		let (slashed, remaining) = <TemplateModule as Currency<u64>>::slash(&1, 60);
+		assert_eq!(remaining, 1);
+		drop(slashed);
+
+		<TemplateModule as LockableCurrency<u64>>::set_lock(STAKING, &1, 0, WithdrawReasons::all());
+		assert!(!Locks::<Test>::contains_key(ASSET, 1));
+		assert_eq!(System::consumers(&1), consumers);
+		assert_eq!(TemplateModule::locked_balance(ASSET, 1), 0);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn create_asset_registers_owner_and_admin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(TemplateModule::create_asset(Origin::signed(1), ASSET, 10, 11), BadOrigin);
+
+		create_asset();
+		assert_eq!(TemplateModule::asset(ASSET), Some(AssetDetails { owner: 10, admin: 11 }));
+		System::assert_last_event(Event::TemplateModule(crate::Event::AssetCreated {
+			asset: ASSET,
+			owner: 10,
+			admin: 11,
+		}));
+
+		assert_noop!(
+			TemplateModule::create_asset(Origin::root(), ASSET, 1, 1),
+			Error::<Test>::AssetExists
+		);
+	});
+}
+
+#[test]
+fn asset_admin_can_mint_and_burn() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		assert_ok!(TemplateModule::mint(Origin::signed(11), ASSET, 1, 100));
+		assert_ok!(TemplateModule::burn(Origin::signed(11), ASSET, 1, 40));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 60);
+
+		assert_noop!(
+			TemplateModule::mint(Origin::signed(10), ASSET, 1, 100),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn asset_owner_can_change_admin() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		assert_noop!(
+			TemplateModule::set_asset_admin(Origin::signed(11), ASSET, 12),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(TemplateModule::set_asset_admin(Origin::signed(10), ASSET, 12));
+
+		assert_ok!(TemplateModule::mint(Origin::signed(12), ASSET, 1, 100));
+		assert_noop!(
+			TemplateModule::mint(Origin::signed(11), ASSET, 1, 100),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn unknown_asset_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TemplateModule::mint(Origin::root(), ASSET, 1, 100),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 0),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			TemplateModule::approve(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			TemplateModule::decrease_allowance(Origin::signed(1), ASSET, 2, 0),
+			Error::<Test>::UnknownAsset
+		);
+	});
+}
+
+#[test]
+fn assets_are_accounted_separately() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::create_asset(Origin::root(), 1, 10, 11));
+
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::mint(Origin::root(), 1, 1, 5));
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 50));
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), 1, 2, 6),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(2), 1, 1, 2, 5),
+			Error::<Test>::InsufficientAllowance
+		);
+
+		assert_eq!(TemplateModule::total_supply(ASSET), 100);
+		assert_eq!(TemplateModule::total_supply(1), 5);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn set_metadata_works_for_admins() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		assert_ok!(TemplateModule::set_metadata(
+			Origin::signed(11),
+			ASSET,
+			b"Token".to_vec(),
+			b"TKN".to_vec(),
+			12
+		));
+		System::assert_last_event(Event::TemplateModule(crate::Event::MetadataSet {
+			asset: ASSET,
+			name: b"Token".to_vec(),
+			symbol: b"TKN".to_vec(),
+			decimals: 12,
+		}));
+		assert_eq!(
+			TemplateModule::asset_metadata(ASSET),
+			Some(AssetMetadata { name: b"Token".to_vec(), symbol: b"TKN".to_vec(), decimals: 12 })
+		);
+
+		assert_ok!(TemplateModule::set_metadata(Origin::root(), ASSET, vec![], vec![], 0));
+		assert_noop!(
+			TemplateModule::set_metadata(Origin::signed(1), ASSET, vec![], vec![], 0),
+			Error::<Test>::NoPermission
+		);
+		assert_eq!(TemplateModule::asset_metadata(1), None);
+	});
+}
+
+#[test]
+fn set_metadata_rejects_long_strings() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		assert_noop!(
+			TemplateModule::set_metadata(Origin::root(), ASSET, vec![0; 9], vec![], 0),
+			Error::<Test>::BadMetadata
+		);
+		assert_noop!(
+			TemplateModule::set_metadata(Origin::root(), ASSET, vec![], vec![0; 9], 0),
+			Error::<Test>::BadMetadata
+		);
+	});
+}
+
+#[test]
+fn genesis_config_seeds_storage() {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		assets: vec![(ASSET, 10, 11)],
+		metadata: vec![(ASSET, b"Token".to_vec(), b"TKN".to_vec(), 12)],
+		balances: vec![(ASSET, 1, 100), (ASSET, 2, 50), (ASSET, 1, 5)],
+		allowances: vec![(ASSET, 1, 2, 30)],
+		something: Some(7),
+		numbers: vec![(1, 42)],
+		oracle_authorities: vec![8],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(TemplateModule::asset(ASSET), Some(AssetDetails { owner: 10, admin: 11 }));
+		assert_eq!(TemplateModule::metadata(ASSET).decimals, 12);
+		assert_eq!(TemplateModule::metadata(ASSET).symbol.into_inner(), b"TKN".to_vec());
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 105);
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 50);
+		assert_eq!(TemplateModule::total_supply(ASSET), 155);
+		assert_eq!(TemplateModule::allowance(ASSET, &1, &2), 30);
+		assert_eq!(TemplateModule::something(), Some(7));
+		assert_eq!(
+			TemplateModule::numbers(1).into_inner(),
+			vec![NumberEntry { value: 42, block: 0 }]
+		);
+		assert_eq!(TemplateModule::oracle_authorities().into_inner(), vec![8]);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+#[should_panic(expected = "Balance in an unknown asset")]
+fn genesis_config_rejects_balances_in_unknown_assets() {
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> { balances: vec![(ASSET, 1, 100)], ..Default::default() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+}
+
+#[test]
+fn faucet_claim_mints_and_enforces_cooldown() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 1));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 100);
+		assert_eq!(TemplateModule::total_supply(ASSET), 100);
+		System::assert_last_event(Event::TemplateModule(crate::Event::FaucetClaimed {
+			who: 1,
+			amount: 100,
+		}));
+
+		System::set_block_number(10);
+		assert_noop!(
+			TemplateModule::faucet_claim(Origin::none(), 1),
+			Error::<Test>::FaucetCooldown
+		);
+
+		System::set_block_number(11);
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 1));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 200);
+	});
+}
+
+#[test]
+fn faucet_claim_is_capped_per_block() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 1));
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 2));
+		assert_noop!(
+			TemplateModule::faucet_claim(Origin::none(), 3),
+			Error::<Test>::FaucetCapReached
+		);
+
+		System::set_block_number(2);
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 3));
+	});
+}
+
+#[test]
+fn faucet_claim_must_be_unsigned_and_enabled() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		assert_noop!(TemplateModule::faucet_claim(Origin::signed(1), 1), BadOrigin);
+
+		FaucetEnabled::set(false);
+		assert_noop!(
+			TemplateModule::faucet_claim(Origin::none(), 1),
+			Error::<Test>::FaucetDisabled
+		);
+		FaucetEnabled::set(true);
+	});
+}
+
+#[test]
+fn faucet_claim_validate_unsigned_filters_spam() {
+	use frame_support::{
+		pallet_prelude::{InvalidTransaction, TransactionSource},
+		unsigned::ValidateUnsigned,
+	};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		let call = crate::Call::faucet_claim { dest: 1 };
+
+		let valid = TemplateModule::validate_unsigned(TransactionSource::External, &call).unwrap();
+		assert_eq!(valid.longevity, 5);
+
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 1));
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Stale.into())
+		);
+
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 2));
+		assert_eq!(
+			TemplateModule::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::faucet_claim { dest: 3 }
+			),
+			Err(InvalidTransaction::ExhaustsResources.into())
+		);
+
+		FaucetEnabled::set(false);
+		System::set_block_number(20);
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Call.into())
+		);
+		FaucetEnabled::set(true);
+	});
+}
+
+#[test]
+fn faucet_claim_requires_an_existing_unpaused_asset() {
+	use frame_support::{
+		pallet_prelude::{InvalidTransaction, TransactionSource},
+		unsigned::ValidateUnsigned,
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = crate::Call::faucet_claim { dest: 1 };
+
+		// `FaucetAsset` has not been created.
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_noop!(TemplateModule::faucet_claim(Origin::none(), 1), Error::<Test>::UnknownAsset);
+
+		create_asset();
+		assert_ok!(TemplateModule::pause(Origin::root(), ASSET));
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Call.into())
+		);
+		assert_noop!(TemplateModule::faucet_claim(Origin::none(), 1), Error::<Test>::Paused);
+
+		assert_ok!(TemplateModule::unpause(Origin::root(), ASSET));
+		assert_ok!(TemplateModule::faucet_claim(Origin::none(), 1));
+	});
+}
+
+#[test]
+fn faucet_claims_compete_for_a_bounded_number_of_pool_slots() {
+	use frame_support::{pallet_prelude::TransactionSource, unsigned::ValidateUnsigned};
+	use std::collections::BTreeSet;
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		// However many accounts claim, they compete for `MaxFaucetClaimsPerBlock` slots.
+		let slots = (1..=10)
+			.map(|dest| {
+				let call = crate::Call::faucet_claim { dest };
+				let valid =
+					TemplateModule::validate_unsigned(TransactionSource::External, &call).unwrap();
+				assert_eq!(valid.provides.len(), 2);
+				valid.provides[1].clone()
+			})
+			.collect::<BTreeSet<_>>();
+		assert_eq!(slots.len(), 2);
+	});
+}
+
+#[test]
+fn frozen_account_cannot_send() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 50));
+
+		assert_noop!(TemplateModule::freeze(Origin::signed(1), ASSET, 1), BadOrigin);
+		assert_ok!(TemplateModule::freeze(Origin::root(), ASSET, 1));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Frozen {
+			asset: ASSET,
+			who: 1,
+		}));
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::Frozen
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(2), ASSET, 1, 3, 10),
+			Error::<Test>::Frozen
+		);
+		// Frozen accounts can still receive.
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 2, 10));
+		assert_ok!(TemplateModule::transfers(Origin::signed(2), ASSET, 1, 10));
+
+		assert_ok!(TemplateModule::thaw(Origin::root(), ASSET, 1));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Thawed {
+			asset: ASSET,
+			who: 1,
+		}));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10));
+	});
+}
+
+#[test]
+fn paused_asset_blocks_transfers_and_approvals() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::approve(Origin::signed(1), ASSET, 2, 50));
+
+		assert_noop!(TemplateModule::pause(Origin::signed(1), ASSET), BadOrigin);
+		assert_ok!(TemplateModule::pause(Origin::root(), ASSET));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Paused { asset: ASSET }));
+
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(2), ASSET, 1, 3, 10),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::approve(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::increase_allowance(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::Paused
+		);
+		assert_noop!(
+			TemplateModule::decrease_allowance(Origin::signed(1), ASSET, 2, 10),
+			Error::<Test>::Paused
+		);
+
+		assert_ok!(TemplateModule::unpause(Origin::root(), ASSET));
+		System::assert_last_event(Event::TemplateModule(crate::Event::Unpaused { asset: ASSET }));
+		assert_ok!(TemplateModule::transfers(Origin::signed(1), ASSET, 2, 10));
+	});
+}
+
+#[test]
+fn frozen_account_cannot_withdraw_through_traits() {
+	use frame_support::traits::{
+		tokens::{fungible::Inspect, WithdrawConsequence},
+		Currency, ExistenceRequirement, WithdrawReasons,
+	};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::freeze(Origin::root(), ASSET, 1));
+
+		assert_eq!(<TemplateModule as Inspect<u64>>::reducible_balance(&1, false), 0);
+		assert_eq!(
+			<TemplateModule as Inspect<u64>>::can_withdraw(&1, 10),
+			WithdrawConsequence::Frozen
+		);
+		assert!(<TemplateModule as Currency<u64>>::withdraw(
+			&1,
+			10,
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::AllowDeath
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn vested_transfer_locks_tokens_until_vested() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		let schedule = VestingInfo { locked: 50, per_block: 10, starting_block: 1 };
+		assert_ok!(TemplateModule::vested_transfer(Origin::signed(1), ASSET, 2, schedule));
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 50);
+		assert_eq!(TemplateModule::vesting_locks(ASSET, 2), 50);
+		System::assert_last_event(Event::TemplateModule(crate::Event::VestingUpdated {
+			asset: ASSET,
+			who: 2,
+			unvested: 50,
+		}));
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(2), ASSET, 3, 1),
+			Error::<Test>::BalanceLocked
+		);
+
+		// Unlocked tokens only become spendable once `vest` is called.
+		System::set_block_number(3);
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(2), ASSET, 3, 20),
+			Error::<Test>::BalanceLocked
+		);
+		assert_ok!(TemplateModule::vest(Origin::signed(2), ASSET));
+		assert_eq!(TemplateModule::vesting_locks(ASSET, 2), 30);
+		assert_ok!(TemplateModule::transfers(Origin::signed(2), ASSET, 3, 20));
+		assert_noop!(
+			TemplateModule::transfers(Origin::signed(2), ASSET, 3, 1),
+			Error::<Test>::BalanceLocked
+		);
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::vest(Origin::signed(2), ASSET));
+		System::assert_last_event(Event::TemplateModule(crate::Event::VestingCompleted {
+			asset: ASSET,
+			who: 2,
+		}));
+		assert_eq!(TemplateModule::vesting(ASSET, 2), None);
+		assert_ok!(TemplateModule::transfers(Origin::signed(2), ASSET, 3, 30));
+		assert_noop!(TemplateModule::vest(Origin::signed(2), ASSET), Error::<Test>::NotVesting);
+	});
+}
+
+#[test]
+fn vesting_lock_is_enforced_in_transfer_from_and_reserve() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		let schedule = VestingInfo { locked: 50, per_block: 1, starting_block: 10 };
+		assert_ok!(TemplateModule::vested_transfer(Origin::signed(1), ASSET, 2, schedule));
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 2, 5));
+		assert_ok!(TemplateModule::approve(Origin::signed(2), ASSET, 3, 100));
+
+		assert_noop!(
+			TemplateModule::transfer_from(Origin::signed(3), ASSET, 2, 3, 6),
+			Error::<Test>::BalanceLocked
+		);
+		assert_ok!(TemplateModule::transfer_from(Origin::signed(3), ASSET, 2, 3, 5));
+		assert_noop!(
+			<TemplateModule as ReservableCurrency<u64>>::reserve(&2, 1),
+			Error::<Test>::BalanceLocked
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_checks_the_consumer_limit_before_moving_funds() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		fund(2);
+		for _ in 0..16 {
+			assert_ok!(System::inc_consumers(&2));
+		}
+
+		let schedule = VestingInfo { locked: 50, per_block: 1, starting_block: 10 };
+		assert_noop!(
+			TemplateModule::vested_transfer(Origin::signed(1), ASSET, 2, schedule),
+			sp_runtime::DispatchError::TooManyConsumers
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_validates_schedule() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_noop!(
+			TemplateModule::vested_transfer(
+				Origin::signed(1),
+				ASSET,
+				2,
+				VestingInfo { locked: 9, per_block: 1, starting_block: 1 }
+			),
+			Error::<Test>::AmountLow
+		);
+		assert_noop!(
+			TemplateModule::vested_transfer(
+				Origin::signed(1),
+				ASSET,
+				2,
+				VestingInfo { locked: 10, per_block: 0, starting_block: 1 }
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+
+		let schedule = VestingInfo { locked: 10, per_block: 1, starting_block: 1 };
+		for _ in 0..3 {
+			assert_ok!(TemplateModule::vested_transfer(
+				Origin::signed(1),
+				ASSET,
+				2,
+				schedule.clone()
+			));
+		}
+		assert_noop!(
+			TemplateModule::vested_transfer(Origin::signed(1), ASSET, 2, schedule),
+			Error::<Test>::AtMaxVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_pays_every_recipient() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_ok!(TemplateModule::transfer_batch(
+			Origin::signed(1),
+			ASSET,
+			vec![(2, 10), (3, 20), (2, 30)]
+		));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 40);
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 40);
+		assert_eq!(TemplateModule::balance_of(ASSET, &3), 20);
+		System::assert_has_event(Event::TemplateModule(crate::Event::Transfer {
+			asset: ASSET,
+			from: 1,
+			to: 3,
+			amount: 20,
+		}));
+		System::assert_last_event(Event::TemplateModule(crate::Event::BatchTransferred {
+			asset: ASSET,
+			from: 1,
+			count: 3,
+			total: 60,
+		}));
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn transfer_batch_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_noop!(
+			TemplateModule::transfer_batch(Origin::signed(1), ASSET, vec![(2, 60), (3, 41)]),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			TemplateModule::transfer_batch(Origin::signed(1), ASSET, vec![(2, u128::MAX), (3, 1)]),
+			Error::<Test>::Overflow
+		);
+		assert_noop!(
+			TemplateModule::transfer_batch(Origin::signed(1), ASSET, vec![(2, 1); 5]),
+			Error::<Test>::TooManyTransfers
+		);
+	});
+}
+
+#[test]
+fn transfer_batch_checks_min_balance_per_recipient() {
+	MinBalance::set(10);
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+
+		assert_noop!(
+			TemplateModule::transfer_batch(Origin::signed(1), ASSET, vec![(2, 6), (3, 4)]),
+			Error::<Test>::BelowMinimum
+		);
+		assert_ok!(TemplateModule::transfer_batch(
+			Origin::signed(1),
+			ASSET,
+			vec![(2, 6), (3, 10), (2, 4)]
+		));
+		assert_eq!(TemplateModule::balance_of(ASSET, &2), 10);
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+	MinBalance::set(1);
+}
+
+/// A permit from `owner` for `spender` over `amount` of `ASSET`, signed over `owner`'s current
+/// nonce.
+fn sign_permit(owner: u64, spender: u64, amount: u128, deadline: u64) -> TestSignature {
+	let nonce = TemplateModule::permit_nonce(owner);
+	TestSignature(
+		owner,
+		TemplateModule::permit_payload(ASSET, &owner, &spender, amount, nonce, deadline),
+	)
+}
+
+#[test]
+fn permit_sets_allowance_for_relayer() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+
+		let signature = sign_permit(1, 2, 50, 10);
+		assert_ok!(TemplateModule::permit(Origin::signed(3), ASSET, 1, 2, 50, 10, signature));
+		assert_eq!(TemplateModule::allowance(ASSET, &1, &2), 50);
+		assert_eq!(TemplateModule::permit_nonce(1), 1);
+		System::assert_last_event(Event::TemplateModule(crate::Event::Approval {
+			asset: ASSET,
+			owner: 1,
+			spender: 2,
+			amount: 50,
+		}));
+	});
+}
+
+#[test]
+fn permit_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+
+		let signature = sign_permit(1, 2, 50, 10);
+		assert_ok!(TemplateModule::permit(
+			Origin::signed(3),
+			ASSET,
+			1,
+			2,
+			50,
+			10,
+			signature.clone()
+		));
+		assert_noop!(
+			TemplateModule::permit(Origin::signed(3), ASSET, 1, 2, 50, 10, signature),
+			Error::<Test>::BadPermitSignature
+		);
+	});
+}
+
+#[test]
+fn permit_rejects_expired_or_forged_permits() {
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(11);
+
+		let signature = sign_permit(1, 2, 50, 10);
+		assert_noop!(
+			TemplateModule::permit(Origin::signed(3), ASSET, 1, 2, 50, 10, signature),
+			Error::<Test>::PermitExpired
+		);
+
+		// Signed by the wrong account, or for a different amount.
+		let forged = TestSignature(4, sign_permit(1, 2, 50, 20).1);
+		assert_noop!(
+			TemplateModule::permit(Origin::signed(3), ASSET, 1, 2, 50, 20, forged),
+			Error::<Test>::BadPermitSignature
+		);
+		let signature = sign_permit(1, 2, 50, 20);
+		assert_noop!(
+			TemplateModule::permit(Origin::signed(3), ASSET, 1, 2, 60, 20, signature),
+			Error::<Test>::BadPermitSignature
+		);
+	});
+}
+
+#[test]
+fn fees_can_be_paid_in_template_tokens() {
+	use crate::ChargeTemplateFee;
+	use frame_support::weights::{DispatchInfo, Pays, PostDispatchInfo};
+	use sp_runtime::{traits::SignedExtension, FixedU128};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 1_000_000_000_000));
+		assert_ok!(TemplateModule::set_fee_rate(Origin::root(), ASSET, Some(FixedU128::from(2))));
+
+		let call = Call::TemplateModule(crate::Call::do_something { something: 1 });
+		let info = DispatchInfo { weight: 1_000, ..Default::default() };
+		let post_info = PostDispatchInfo { actual_weight: Some(400), pays_fee: Pays::Yes };
+		let fee = TransactionPayment::compute_fee(10, &info, 0);
+		let actual_fee = TransactionPayment::compute_actual_fee(10, &info, &post_info, 0);
+		assert!(actual_fee < fee);
+
+		let pre = ChargeTemplateFee::<Test>::from(0, Some(ASSET))
+			.pre_dispatch(&1, &call, &info, 10)
+			.unwrap();
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 1_000_000_000_000 - 2 * fee);
+
+		// The part of the fee not used by the actual weight is refunded.
+		assert_ok!(ChargeTemplateFee::<Test>::post_dispatch(
+			Some(pre),
+			&info,
+			&post_info,
+			10,
+			&Ok(())
+		));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 1_000_000_000_000 - 2 * actual_fee);
+		assert_eq!(TemplateModule::balance_of(ASSET, &FEE_COLLECTOR), 2 * actual_fee);
+		System::assert_last_event(Event::TemplateModule(crate::Event::FeePaid {
+			asset: ASSET,
+			who: 1,
+			actual_fee: 2 * actual_fee,
+		}));
+		assert_eq!(TemplateModule::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn fees_in_template_tokens_require_a_rate_and_funds() {
+	use crate::ChargeTemplateFee;
+	use frame_support::weights::DispatchInfo;
+	use sp_runtime::{
+		traits::SignedExtension, transaction_validity::InvalidTransaction, FixedU128,
+	};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		let call = Call::TemplateModule(crate::Call::do_something { something: 1 });
+		let info = DispatchInfo { weight: 1_000, ..Default::default() };
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 1_000_000_000_000));
+
+		assert_eq!(
+			ChargeTemplateFee::<Test>::from(0, Some(ASSET)).validate(&1, &call, &info, 10),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		assert_ok!(TemplateModule::set_fee_rate(Origin::root(), ASSET, Some(FixedU128::from(2))));
+		assert_ok!(ChargeTemplateFee::<Test>::from(0, Some(ASSET)).validate(&1, &call, &info, 10));
+		assert_eq!(
+			ChargeTemplateFee::<Test>::from(0, Some(ASSET)).validate(&2, &call, &info, 10),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		assert_noop!(TemplateModule::set_fee_rate(Origin::signed(11), ASSET, None), BadOrigin);
+	});
+}
+
+#[test]
+fn feeless_calls_withdraw_nothing_in_template_tokens() {
+	use crate::ChargeTemplateFee;
+	use frame_support::weights::{DispatchInfo, Pays, PostDispatchInfo};
+	use sp_runtime::{traits::SignedExtension, FixedU128};
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 100));
+		assert_ok!(TemplateModule::set_fee_rate(Origin::root(), ASSET, Some(FixedU128::from(2))));
+		assert_ok!(TemplateModule::freeze(Origin::root(), ASSET, 1));
+		System::reset_events();
+
+		let call = Call::TemplateModule(crate::Call::do_something { something: 1 });
+		let info = DispatchInfo { weight: 1_000, pays_fee: Pays::No, ..Default::default() };
+		let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::No };
+
+		// A frozen account can still send feeless calls, as long as it tips nothing.
+		let pre = ChargeTemplateFee::<Test>::from(0, Some(ASSET))
+			.pre_dispatch(&1, &call, &info, 10)
+			.unwrap();
+		assert_ok!(ChargeTemplateFee::<Test>::post_dispatch(
+			Some(pre),
+			&info,
+			&post_info,
+			10,
+			&Ok(())
+		));
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 100);
+		assert!(System::events().is_empty());
+		assert!(ChargeTemplateFee::<Test>::from(1, Some(ASSET))
+			.validate(&1, &call, &info, 10)
+			.is_err());
+	});
+}
+
+#[test]
+fn fees_without_an_asset_are_paid_in_the_native_token() {
+	use crate::ChargeTemplateFee;
+	use frame_support::{traits::Currency, weights::DispatchInfo};
+	use sp_runtime::traits::SignedExtension;
+
+	new_test_ext().execute_with(|| {
+		create_asset();
+		assert_ok!(TemplateModule::mint(Origin::root(), ASSET, 1, 1_000_000_000_000));
+		let _ = NativeBalances::deposit_creating(&1, 1_000_000_000_000);
+
+		let call = Call::TemplateModule(crate::Call::do_something { something: 1 });
+		let info = DispatchInfo { weight: 1_000, ..Default::default() };
+		let fee = TransactionPayment::compute_fee(10, &info, 0);
+
+		assert!(ChargeTemplateFee::<Test>::from(0, None)
+			.pre_dispatch(&1, &call, &info, 10)
+			.is_ok());
+		assert_eq!(NativeBalances::free_balance(&1), 1_000_000_000_000 - fee);
+		assert_eq!(TemplateModule::balance_of(ASSET, &1), 1_000_000_000_000);
+	});
+}
+
+/// Run `test` with an offchain worker whose oracle endpoint answers with `body`, and a
+/// transaction pool collecting whatever it submits. Oracle key `7` is in the keystore, and its
+/// account is the only oracle authority.
+fn oracle_ext(
+	body: &[u8],
+	test: impl FnOnce(std::sync::Arc<parking_lot::RwLock<sp_core::offchain::testing::PoolState>>),
+) {
+	use sp_core::offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	offchain_state.write().expect_request(PendingRequest {
+		method: "GET".into(),
+		uri: ORACLE_URL.into(),
+		response: Some(body.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	sp_runtime::testing::UintAuthorityId::set_all_keys(vec![7]);
+
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.execute_with(|| {
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![7]));
+		test(pool_state)
+	});
+}
+
+#[test]
+fn offchain_worker_submits_unsigned_oracle_value() {
+	use codec::Decode;
+	use frame_support::traits::OffchainWorker;
+
+	oracle_ext(b"42\n", |pool_state| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, None);
+		match tx.call {
+			Call::TemplateModule(crate::Call::submit_oracle_value_unsigned {
+				payload,
+				_signature: signature,
+			}) => {
+				assert_eq!(payload, OraclePayload { public: 7.into(), block_number: 1, value: 42 });
+				assert_eq!(signature, TestSignature(7, codec::Encode::encode(&payload)));
+			},
+			call => panic!("unexpected call: {:?}", call),
+		}
+	});
+}
+
+#[test]
+fn offchain_worker_submits_signed_oracle_value() {
+	use codec::Decode;
+	use frame_support::traits::OffchainWorker;
+
+	SignedOracleSubmissions::set(true);
+	oracle_ext(b"42", |pool_state| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature, Some((0, ())));
+		assert_eq!(tx.call, Call::TemplateModule(crate::Call::submit_oracle_value { value: 42 }));
+	});
+	SignedOracleSubmissions::set(false);
+}
+
+#[test]
+fn offchain_worker_skips_bad_responses_and_early_blocks() {
+	use frame_support::traits::OffchainWorker;
+
+	oracle_ext(b"not a number", |pool_state| {
+		System::set_block_number(1);
+		TemplateModule::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// No request is expected before the next interval starts.
+		assert_ok!(TemplateModule::submit_oracle_value(Origin::signed(7), 1));
+		System::set_block_number(2);
+		TemplateModule::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn oracle_submissions_are_validated_and_rate_limited() {
+	use frame_support::{
+		pallet_prelude::{InvalidTransaction, TransactionSource},
+		unsigned::ValidateUnsigned,
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![7]));
+		let payload = OraclePayload { public: 7.into(), block_number: 1, value: 42 };
+		let signature = TestSignature(7, codec::Encode::encode(&payload));
+		let call = crate::Call::submit_oracle_value_unsigned {
+			payload: payload.clone(),
+			_signature: signature.clone(),
+		};
+
+		let valid = TemplateModule::validate_unsigned(TransactionSource::External, &call).unwrap();
+		assert_eq!(valid.priority, 100);
+		assert_eq!(valid.longevity, 5);
+
+		let forged = crate::Call::submit_oracle_value_unsigned {
+			payload: payload.clone(),
+			_signature: TestSignature(8, codec::Encode::encode(&payload)),
+		};
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &forged),
+			Err(InvalidTransaction::BadProof.into())
+		);
+
+		assert_ok!(TemplateModule::submit_oracle_value_unsigned(
+			Origin::none(),
+			payload.clone(),
+			signature
+		));
+		assert_eq!(TemplateModule::oracle_value(), Some(42));
+		assert_eq!(TemplateModule::next_oracle_at(), 6);
+		System::assert_last_event(Event::TemplateModule(crate::Event::OracleValueSubmitted {
+			who: None,
+			value: 42,
+		}));
+
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::Stale.into())
+		);
+		assert_noop!(
+			TemplateModule::submit_oracle_value(Origin::signed(7), 43),
+			Error::<Test>::OracleTooEarly
+		);
+
+		System::set_block_number(6);
+		assert_ok!(TemplateModule::submit_oracle_value(Origin::signed(7), 43));
+		assert_eq!(TemplateModule::oracle_value(), Some(43));
+		System::assert_last_event(Event::TemplateModule(crate::Event::OracleValueSubmitted {
+			who: Some(7),
+			value: 43,
+		}));
+	});
+}
+
+#[test]
+fn only_oracle_authorities_can_submit_values() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			TemplateModule::submit_oracle_value(Origin::signed(1), 42),
+			Error::<Test>::NotOracleAuthority
+		);
+
+		assert_noop!(TemplateModule::set_oracle_authorities(Origin::signed(1), vec![1]), BadOrigin);
+		assert_noop!(
+			TemplateModule::set_oracle_authorities(Origin::root(), vec![1, 2, 3, 4, 5]),
+			Error::<Test>::TooManyOracleAuthorities
+		);
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![1, 2]));
+		System::assert_last_event(Event::TemplateModule(crate::Event::OracleAuthoritiesSet {
+			authorities: vec![1, 2],
+		}));
+		assert_eq!(TemplateModule::oracle_authorities().into_inner(), vec![1, 2]);
+
+		// An unauthorized account cannot push the next interval back either.
+		assert_noop!(
+			TemplateModule::submit_oracle_value(Origin::signed(3), 42),
+			Error::<Test>::NotOracleAuthority
+		);
+		assert_eq!(TemplateModule::next_oracle_at(), 0);
+		assert_ok!(TemplateModule::submit_oracle_value(Origin::signed(2), 42));
+		assert_eq!(TemplateModule::oracle_value(), Some(42));
+	});
+}
+
+#[test]
+fn oracle_payloads_must_be_signed_by_an_authority_key() {
+	use frame_support::{
+		pallet_prelude::{InvalidTransaction, TransactionSource},
+		unsigned::ValidateUnsigned,
+	};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![7]));
+
+		// A validly signed payload from a key generated by anyone else.
+		let payload = OraclePayload { public: 8.into(), block_number: 1, value: 42 };
+		let signature = TestSignature(8, codec::Encode::encode(&payload));
+		let call = crate::Call::submit_oracle_value_unsigned {
+			payload: payload.clone(),
+			_signature: signature.clone(),
+		};
+		assert_eq!(
+			TemplateModule::validate_unsigned(TransactionSource::External, &call),
+			Err(InvalidTransaction::BadProof.into())
+		);
+		assert_noop!(
+			TemplateModule::submit_oracle_value_unsigned(Origin::none(), payload, signature),
+			Error::<Test>::NotOracleAuthority
+		);
+		assert_eq!(TemplateModule::oracle_value(), None);
+	});
+}
+
+#[test]
+fn offchain_worker_only_signs_with_authorized_keys() {
+	use frame_support::traits::OffchainWorker;
+
+	oracle_ext(b"42", |pool_state| {
+		System::set_block_number(1);
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![8]));
+		TemplateModule::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		assert_ok!(TemplateModule::set_oracle_authorities(Origin::root(), vec![8, 7]));
+		TemplateModule::offchain_worker(1);
+		assert_eq!(pool_state.read().transactions.len(), 1);
+	});
+}
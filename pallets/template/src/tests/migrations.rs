@@ -0,0 +1,172 @@
+use crate::{
+	migrations::{v0, v1, v2, v3, v4, InitializeInstance, Migrations},
+	mock::{new_test_ext, Test},
+	AccountData, AssetDetails, Assets, Balances, NumberEntry, Pallet, TotalSupply, Vesting,
+	VestingInfo,
+};
+use frame_support::{
+	instances::Instance1,
+	traits::{ConstU64, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+
+#[test]
+fn v1_moves_single_asset_layout_into_default_asset() {
+	new_test_ext().execute_with(|| {
+		v0::Balances::<Test>::insert(1, 70);
+		v0::Balances::<Test>::insert(2, 30);
+		v0::TotalSupply::<Test>::put(100);
+		v0::Allowances::<Test>::insert(1, 2, 20);
+
+		v1::MigrateToMultiAsset::<Test, ConstU64<99>>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+		assert_eq!(Pallet::<Test>::asset(0), Some(AssetDetails { owner: 99, admin: 99 }));
+		assert_eq!(v1::Balances::<Test>::get(0, 1), 70);
+		assert_eq!(v1::Balances::<Test>::get(0, 2), 30);
+		assert_eq!(Pallet::<Test>::total_supply(0), 100);
+		assert_eq!(Pallet::<Test>::allowance(0, &1, &2), 20);
+	});
+}
+
+#[test]
+fn v1_sums_balances_into_total_supply() {
+	new_test_ext().execute_with(|| {
+		v0::Balances::<Test>::insert(1, 70);
+		v0::Balances::<Test>::insert(2, 30);
+
+		v1::MigrateToMultiAsset::<Test, ConstU64<99>>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::total_supply(0), 100);
+		assert!(!v0::TotalSupply::<Test>::exists());
+	});
+}
+
+#[test]
+fn v1_ignores_a_stale_total_supply() {
+	new_test_ext().execute_with(|| {
+		v0::Balances::<Test>::insert(1, 70);
+		v0::TotalSupply::<Test>::put(20);
+
+		Migrations::<Test, ConstU64<99>>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::total_supply(0), 70);
+		assert!(!v0::TotalSupply::<Test>::exists());
+		assert_eq!(Pallet::<Test>::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn migrations_bring_single_asset_layout_to_current_version() {
+	new_test_ext().execute_with(|| {
+		v0::Balances::<Test>::insert(1, 70);
+		v0::Balances::<Test>::insert(2, 30);
+		v0::TotalSupply::<Test>::put(100);
+
+		Migrations::<Test, ConstU64<99>>::on_runtime_upgrade();
+
+		assert_eq!(
+			StorageVersion::get::<Pallet<Test>>(),
+			Pallet::<Test>::current_storage_version()
+		);
+		assert_eq!(Pallet::<Test>::balances(0, 1), AccountData { free: 70, reserved: 0 });
+		assert_eq!(Pallet::<Test>::do_try_state(), Ok(()));
+
+		// Running them again is a no-op.
+		Migrations::<Test, ConstU64<99>>::on_runtime_upgrade();
+		assert_eq!(Pallet::<Test>::balances(0, 2), AccountData { free: 30, reserved: 0 });
+	});
+}
+
+#[test]
+fn v2_moves_balances_into_free_balance() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		Assets::<Test>::insert(0, AssetDetails { owner: 99, admin: 99 });
+		TotalSupply::<Test>::insert(0, 100);
+		v1::Balances::<Test>::insert(0, 1, 70);
+		v1::Balances::<Test>::insert(0, 2, 30);
+
+		v2::MigrateToAccountData::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 2);
+		assert_eq!(Pallet::<Test>::balances(0, 1), AccountData { free: 70, reserved: 0 });
+		assert_eq!(Pallet::<Test>::balances(0, 2), AccountData { free: 30, reserved: 0 });
+		assert_eq!(Pallet::<Test>::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn v3_turns_numbers_into_histories() {
+	new_test_ext().execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(5);
+		StorageVersion::new(2).put::<Pallet<Test>>();
+		v2::Numbers::<Test>::insert(1, 42);
+		v2::Numbers::<Test>::insert(2, 0);
+
+		v3::MigrateToNumberHistory::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 3);
+		assert_eq!(
+			Pallet::<Test>::numbers(1).into_inner(),
+			vec![NumberEntry { value: 42, block: 5 }]
+		);
+		assert_eq!(
+			Pallet::<Test>::numbers(2).into_inner(),
+			vec![NumberEntry { value: 0, block: 5 }]
+		);
+	});
+}
+
+#[test]
+fn v4_adds_references_and_removes_empty_balances() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<Pallet<Test>>();
+		Assets::<Test>::insert(0, AssetDetails { owner: 99, admin: 99 });
+		TotalSupply::<Test>::insert(0, 100);
+		Balances::<Test>::insert(0, 1, AccountData { free: 70, reserved: 0 });
+		Balances::<Test>::insert(0, 2, AccountData { free: 0, reserved: 30 });
+		Balances::<Test>::insert(0, 3, AccountData::default());
+		let schedule = VestingInfo { locked: 10, per_block: 1, starting_block: 0 };
+		Vesting::<Test>::insert(0, 1, BoundedVec::try_from(vec![schedule]).unwrap());
+
+		v4::MigrateToAccountLifecycle::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 4);
+		assert!(!Balances::<Test>::contains_key(0, 3));
+		assert_eq!(frame_system::Pallet::<Test>::providers(&1), 1);
+		assert_eq!(frame_system::Pallet::<Test>::consumers(&1), 1);
+		assert_eq!(frame_system::Pallet::<Test>::providers(&2), 1);
+		assert!(!frame_system::Pallet::<Test>::account_exists(&3));
+		assert_eq!(Pallet::<Test>::do_try_state(), Ok(()));
+	});
+}
+
+#[test]
+fn initialize_instance_puts_the_current_version_on_a_new_instance() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StorageVersion::get::<Pallet<Test, Instance1>>(), 0);
+
+		InitializeInstance::<Test, Instance1>::on_runtime_upgrade();
+
+		assert_eq!(
+			StorageVersion::get::<Pallet<Test, Instance1>>(),
+			Pallet::<Test, Instance1>::current_storage_version()
+		);
+		// The default instance is migrated by `Migrations` instead.
+		assert_eq!(StorageVersion::get::<Pallet<Test>>(), 0);
+	});
+}
+
+#[test]
+fn initialize_instance_leaves_versioned_or_populated_instances_alone() {
+	new_test_ext().execute_with(|| {
+		Assets::<Test, Instance1>::insert(0, AssetDetails { owner: 99, admin: 99 });
+		InitializeInstance::<Test, Instance1>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Pallet<Test, Instance1>>(), 0);
+
+		StorageVersion::new(1).put::<Pallet<Test, Instance1>>();
+		InitializeInstance::<Test, Instance1>::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get::<Pallet<Test, Instance1>>(), 1);
+	});
+}
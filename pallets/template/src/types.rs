@@ -0,0 +1,98 @@
+//! Various basic types for use in the template pallet.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::LockIdentifier, RuntimeDebug};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{AtLeast32BitUnsigned, SaturatedConversion};
+
+/// Ownership of an asset issued by this pallet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetails<AccountId> {
+	/// Can hand the asset over to a new admin.
+	pub owner: AccountId,
+	/// Can mint and burn the asset alongside `MintOrigin`/`BurnOrigin`.
+	pub admin: AccountId,
+}
+
+/// The balance of an account in one asset.
+#[derive(
+	Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct AccountData {
+	/// Tokens the account can spend.
+	pub free: u128,
+	/// Tokens set aside on behalf of the account. They still count towards its total balance,
+	/// but cannot be moved until they are unreserved.
+	pub reserved: u128,
+}
+
+impl AccountData {
+	/// The free and reserved balance together.
+	pub fn total(&self) -> u128 {
+		self.free.saturating_add(self.reserved)
+	}
+}
+
+/// A lock on an account's free balance, placed through `LockableCurrency`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct BalanceLock {
+	/// Identifies the lock, so it can be changed or removed.
+	pub id: LockIdentifier,
+	/// The part of the free balance that cannot be withdrawn while the lock is in place.
+	pub amount: u128,
+}
+
+/// Display information for an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetMetadata<BoundedString> {
+	/// The user friendly name of this asset.
+	pub name: BoundedString,
+	/// The ticker symbol for this asset.
+	pub symbol: BoundedString,
+	/// The number of decimals this asset uses to represent one unit.
+	pub decimals: u8,
+}
+
+/// A number stored by an account, and the block it was stored in.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct NumberEntry<BlockNumber> {
+	/// The stored number.
+	pub value: u32,
+	/// The block in which it was stored.
+	pub block: BlockNumber,
+}
+
+/// A schedule that unlocks `locked` tokens linearly, `per_block` at a time, from
+/// `starting_block` onwards.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingInfo<BlockNumber> {
+	/// Tokens locked at the start of the schedule.
+	pub locked: u128,
+	/// Tokens unlocked by every block after `starting_block`.
+	pub per_block: u128,
+	/// The block from which tokens start to unlock.
+	pub starting_block: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> VestingInfo<BlockNumber> {
+	/// Whether the schedule locks anything and ever unlocks it.
+	pub fn is_valid(&self) -> bool {
+		self.locked != 0 && self.per_block != 0
+	}
+
+	/// The amount still locked by this schedule at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> u128 {
+		let vested_blocks: u128 = n.saturating_sub(self.starting_block).saturated_into();
+		self.locked.saturating_sub(vested_blocks.saturating_mul(self.per_block))
+	}
+}
+
+/// Handler for the dust left behind when a balance drops below `MinBalance` and is removed.
+pub trait OnDust<AssetId, AccountId> {
+	/// `amount` of `asset` held by `who` was taken out of `TotalSupply` as dust.
+	fn on_dust(asset: AssetId, who: &AccountId, amount: u128);
+}
+
+impl<AssetId, AccountId> OnDust<AssetId, AccountId> for () {
+	fn on_dust(_: AssetId, _: &AccountId, _: u128) {}
+}
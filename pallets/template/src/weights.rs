@@ -0,0 +1,633 @@
+//! Weights for pallet_template
+//!
+//! These weights are estimates worked out by hand from the storage accesses of each call, listed
+//! above each function, not benchmark results. Before relying on them, regenerate this file on
+//! reference hardware from the benchmarks in `benchmarking.rs`:
+//!
+//! ```text
+//! cargo build --release --features runtime-benchmarks
+//! ./target/release/node-template benchmark pallet \
+//!     --chain=dev \
+//!     --pallet=pallet_template \
+//!     --extrinsic='*' \
+//!     --steps=50 \
+//!     --repeat=20 \
+//!     --output=pallets/template/src/weights.rs \
+//!     --template=frame-weight-template.hbs
+//! ```
+//!
+//! `frame-weight-template.hbs` is the template in Substrate's `.maintain` directory, which also
+//! emits the `WeightInfo` trait, the `()` implementation and the `// Storage:` annotations.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_template.
+pub trait WeightInfo {
+	fn do_something() -> Weight;
+	fn put_number() -> Weight;
+	fn remove_number() -> Weight;
+	fn clear_numbers() -> Weight;
+	fn reap_numbers() -> Weight;
+	fn create_asset() -> Weight;
+	fn set_asset_admin() -> Weight;
+	fn set_metadata() -> Weight;
+	fn set_balances() -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfers() -> Weight;
+	fn transfer_batch(b: u32, ) -> Weight;
+	fn approve() -> Weight;
+	fn permit() -> Weight;
+	fn increase_allowance() -> Weight;
+	fn decrease_allowance() -> Weight;
+	fn transfer_from() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn vested_transfer() -> Weight;
+	fn vest() -> Weight;
+	fn set_fee_rate() -> Weight;
+	fn faucet_claim() -> Weight;
+	fn snapshot() -> Weight;
+	fn submit_oracle_value() -> Weight;
+	fn submit_oracle_value_unsigned() -> Weight;
+	fn set_oracle_authorities(a: u32, ) -> Weight;
+	fn cause_error() -> Weight;
+}
+
+/// Weights for pallet_template using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: TemplateModule Something (r:0 w:1)
+	fn do_something() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	fn put_number() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_number() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_numbers() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	fn reap_numbers() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:1)
+	fn create_asset() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:1)
+	fn set_asset_admin() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Metadata (r:0 w:1)
+	fn set_metadata() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn set_balances() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn mint() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn burn() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfers() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfer_batch(b: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((11_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:0 w:1)
+	fn approve() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule PermitNonces (r:1 w:1)
+	// Storage: TemplateModule Allowances (r:0 w:1)
+	fn permit() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	fn increase_allowance() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	fn decrease_allowance() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfer_from() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:0 w:1)
+	fn freeze() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule FrozenAccounts (r:0 w:1)
+	fn thaw() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:0 w:1)
+	fn pause() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule PausedAssets (r:0 w:1)
+	fn unpause() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Vesting (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:1)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: TemplateModule Vesting (r:1 w:1)
+	// Storage: TemplateModule VestingLocks (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule FeeRates (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule LastFaucetClaim (r:1 w:1)
+	// Storage: TemplateModule FaucetClaims (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn faucet_claim() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:1)
+	// Storage: TemplateModule SnapshotBlocks (r:0 w:1)
+	fn snapshot() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:1 w:0)
+	// Storage: TemplateModule NextOracleAt (r:1 w:1)
+	// Storage: TemplateModule OracleValue (r:0 w:1)
+	fn submit_oracle_value() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:1 w:0)
+	// Storage: TemplateModule NextOracleAt (r:1 w:1)
+	// Storage: TemplateModule OracleValue (r:0 w:1)
+	// Includes the sr25519 signature check of `validate_unsigned`.
+	fn submit_oracle_value_unsigned() -> Weight {
+		(75_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:0 w:1)
+	fn set_oracle_authorities(a: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Something (r:1 w:1)
+	fn cause_error() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: TemplateModule Something (r:0 w:1)
+	fn do_something() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	fn put_number() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_number() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_numbers() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule Numbers (r:1 w:1)
+	// Storage: TemplateModule NumberDeposits (r:1 w:1)
+	fn reap_numbers() -> Weight {
+		(37_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:1)
+	fn create_asset() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:1)
+	fn set_asset_admin() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Metadata (r:0 w:1)
+	fn set_metadata() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn set_balances() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn mint() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn burn() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfers() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfer_batch(b: u32, ) -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add((11_000_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:0 w:1)
+	fn approve() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule PermitNonces (r:1 w:1)
+	// Storage: TemplateModule Allowances (r:0 w:1)
+	fn permit() -> Weight {
+		(71_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	fn increase_allowance() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	fn decrease_allowance() -> Weight {
+		(25_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Allowances (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:0)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn transfer_from() -> Weight {
+		(54_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:0 w:1)
+	fn freeze() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule FrozenAccounts (r:0 w:1)
+	fn thaw() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:0 w:1)
+	fn pause() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule PausedAssets (r:0 w:1)
+	fn unpause() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Vesting (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule FrozenAccounts (r:1 w:0)
+	// Storage: TemplateModule Balances (r:2 w:2)
+	// Storage: TemplateModule VestingLocks (r:1 w:1)
+	// Storage: TemplateModule Locks (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:2 w:2)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: TemplateModule Vesting (r:1 w:1)
+	// Storage: TemplateModule VestingLocks (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule FeeRates (r:0 w:1)
+	fn set_fee_rate() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule LastFaucetClaim (r:1 w:1)
+	// Storage: TemplateModule FaucetClaims (r:1 w:1)
+	// Storage: TemplateModule Assets (r:1 w:0)
+	// Storage: TemplateModule PausedAssets (r:1 w:0)
+	// Storage: TemplateModule TotalSupply (r:1 w:1)
+	// Storage: TemplateModule Balances (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:0)
+	// Storage: TemplateModule BalanceSnapshots (r:1 w:1)
+	// Storage: TemplateModule SupplySnapshots (r:1 w:1)
+	fn faucet_claim() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: TemplateModule CurrentSnapshot (r:1 w:1)
+	// Storage: TemplateModule SnapshotBlocks (r:0 w:1)
+	fn snapshot() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:1 w:0)
+	// Storage: TemplateModule NextOracleAt (r:1 w:1)
+	// Storage: TemplateModule OracleValue (r:0 w:1)
+	fn submit_oracle_value() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:1 w:0)
+	// Storage: TemplateModule NextOracleAt (r:1 w:1)
+	// Storage: TemplateModule OracleValue (r:0 w:1)
+	// Includes the sr25519 signature check of `validate_unsigned`.
+	fn submit_oracle_value_unsigned() -> Weight {
+		(75_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TemplateModule OracleAuthorities (r:0 w:1)
+	fn set_oracle_authorities(a: u32, ) -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add((100_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TemplateModule Something (r:1 w:1)
+	fn cause_error() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use frame_support::instances::Instance1;
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -14,7 +15,10 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdLookup, BlakeTwo256, Block as BlockT, Extrinsic as ExtrinsicT, IdentifyAccount,
+		NumberFor, SaturatedConversion, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -27,7 +31,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
+		StorageInfo,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -36,6 +41,7 @@ pub use frame_support::{
 	StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::CurrencyAdapter;
@@ -99,12 +105,14 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped whenever the encoding of extrinsics changes: version 2 signs `ChargeTemplateFee`
+	//   in place of `ChargeTransactionPayment` and changes the calls of the template pallet.
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -261,9 +269,172 @@ impl pallet_sudo::Config for Runtime {
 	type Call = Call;
 }
 
+parameter_types! {
+	/// Owner and admin of the template asset created from the single-asset layout. Nobody holds
+	/// the key to the all-zero account, so that asset stays governed by its mint/burn origins.
+	pub TemplateLegacyAssetOwner: AccountId = AccountId::new([0u8; 32]);
+	/// Collects transaction fees paid in template tokens. Point this at a treasury account
+	/// before relying on those fees.
+	pub TemplateFeeCollector: AccountId = AccountId::new(*b"modl/template/fee/collector/0000");
+	/// Where the template oracle's offchain workers fetch values from.
+	pub const TemplateOracleUrl: &'static str = "http://localhost:8000/oracle";
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type AssetId = u32;
+	type HoldReason = [u8; 8];
+	type MaxLocks = ConstU32<50>;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type MintOrigin = EnsureRoot<AccountId>;
+	type BurnOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type StringLimit = ConstU32<32>;
+	// A thousandth of a token with 12 decimals.
+	type MinBalance = ConstU128<1_000_000_000>;
+	type OnDust = ();
+	// This runtime only backs dev and test chains, so testers may use the faucet.
+	type FaucetEnabled = ConstBool<true>;
+	type FaucetAsset = ConstU32<0>;
+	type FaucetAmount = ConstU128<{ 100 * 1_000_000_000_000 }>;
+	type FaucetCooldown = ConstU32<HOURS>;
+	type MaxFaucetClaimsPerBlock = ConstU32<10>;
+	type MinVestedTransfer = ConstU128<1_000_000_000_000>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type MaxNumbers = ConstU32<100>;
+	type NativeCurrency = Balances;
+	type NumberDeposit = ConstU128<1_000_000_000_000>;
+	type MaxBatchSize = ConstU32<256>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	type FeeCollector = TemplateFeeCollector;
+	type OracleEnabled = ConstBool<true>;
+	type AuthorityId = pallet_template::crypto::OracleAuthId;
+	type OracleUrl = TemplateOracleUrl;
+	type OracleInterval = ConstU32<10>;
+	type OracleUnsignedPriority = ConstU64<{ u64::MAX / 2 }>;
+	// Unsigned submissions let oracle keys work without funding their accounts.
+	type SignedOracleSubmissions = ConstBool<false>;
+	type MaxOracleAuthorities = ConstU32<16>;
+	// Gives governance a daily set of balances to weigh votes with.
+	type SnapshotPeriod = ConstU32<DAYS>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TemplatePermitSigner;
+}
+
+/// A second template token for rewards, with its own assets, balances and events.
+impl pallet_template::Config<Instance1> for Runtime {
+	type Event = Event;
+	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type AssetId = u32;
+	type HoldReason = [u8; 8];
+	type MaxLocks = ConstU32<50>;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type MintOrigin = EnsureRoot<AccountId>;
+	type BurnOrigin = EnsureRoot<AccountId>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	type StringLimit = ConstU32<32>;
+	type MinBalance = ConstU128<1_000_000_000>;
+	type OnDust = ();
+	// Rewards are paid out by their admins rather than claimed.
+	type FaucetEnabled = ConstBool<false>;
+	type FaucetAsset = ConstU32<0>;
+	type FaucetAmount = ConstU128<0>;
+	type FaucetCooldown = ConstU32<HOURS>;
+	type MaxFaucetClaimsPerBlock = ConstU32<0>;
+	type MinVestedTransfer = ConstU128<1_000_000_000_000>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type MaxNumbers = ConstU32<100>;
+	type NativeCurrency = Balances;
+	type NumberDeposit = ConstU128<1_000_000_000_000>;
+	type MaxBatchSize = ConstU32<256>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	type FeeCollector = TemplateFeeCollector;
+	// `TemplateModule` already runs the oracle, and the same keys would fetch the same values.
+	type OracleEnabled = ConstBool<false>;
+	type AuthorityId = pallet_template::crypto::OracleAuthId;
+	type OracleUrl = TemplateOracleUrl;
+	type OracleInterval = ConstU32<10>;
+	type OracleUnsignedPriority = ConstU64<{ u64::MAX / 2 }>;
+	type SignedOracleSubmissions = ConstBool<false>;
+	type MaxOracleAuthorities = ConstU32<16>;
+	type SnapshotPeriod = ConstU32<0>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TemplatePermitSigner;
+}
+
+/// Signs benchmark permits with an sr25519 key generated in the benchmarking keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TemplatePermitSigner;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_template::BenchmarkHelper<AccountId, Signature> for TemplatePermitSigner {
+	fn signer() -> AccountId {
+		let public = sp_io::crypto::sr25519_generate(TEMPLATE_PERMIT_KEY, None);
+		AccountId::new(public.0)
+	}
+
+	fn sign(signer: &AccountId, message: &[u8]) -> Signature {
+		let public = sp_core::sr25519::Public(<[u8; 32]>::from(signer.clone()));
+		sp_io::crypto::sr25519_sign(TEMPLATE_PERMIT_KEY, &public, message)
+			.expect("the key was generated by `signer`; qed")
+			.into()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+const TEMPLATE_PERMIT_KEY: KeyTypeId = KeyTypeId(*b"tprm");
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	Call: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: Call,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Index,
+	) -> Option<(Call, <UncheckedExtrinsic as ExtrinsicT>::SignaturePayload)> {
+		use codec::Encode;
+
+		// The longest era allowed by `BlockHashCount`, starting from the parent block.
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_template::ChargeTemplateFee::<Runtime>::from(0, None),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, extra, _) = raw_payload.deconstruct();
+
+		Some((call, (sp_runtime::MultiAddress::Id(account), signature, extra)))
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -283,6 +454,7 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		// Include the custom logic from the pallet-template in the runtime.
 		TemplateModule: pallet_template,
+		RewardToken: pallet_template::<Instance1>,
 	}
 );
 
@@ -301,12 +473,20 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_template::ChargeTemplateFee<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
+
+/// Storage migrations applied on runtime upgrade. `RewardToken` was added to running chains
+/// without a genesis, so it only needs its storage version set.
+type Migrations = (
+	pallet_template::migrations::Migrations<Runtime, TemplateLegacyAssetOwner>,
+	pallet_template::migrations::InitializeInstance<Runtime, Instance1>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -314,6 +494,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -447,6 +628,45 @@ impl_runtime_apis! {
 		}
 	}
 
+	// Serves `TemplateModule` only. `RewardToken` is not exposed through the template RPC.
+	impl pallet_template_rpc_runtime_api::TemplateApi<Block, u32, AccountId, BlockNumber> for Runtime {
+		fn metadata(asset: u32) -> Option<pallet_template_rpc_runtime_api::AssetMetadata<Vec<u8>>> {
+			TemplateModule::asset_metadata(asset)
+		}
+
+		fn balance_of(asset: u32, who: AccountId) -> u128 {
+			TemplateModule::balance_of(asset, &who)
+		}
+
+		fn allowance(asset: u32, owner: AccountId, spender: AccountId) -> u128 {
+			TemplateModule::allowance(asset, &owner, &spender)
+		}
+
+		fn total_supply(asset: u32) -> u128 {
+			TemplateModule::total_supply(asset)
+		}
+
+		fn balance_of_at(asset: u32, who: AccountId, id: u32) -> Option<u128> {
+			TemplateModule::balance_of_at(asset, &who, id)
+		}
+
+		fn total_supply_at(asset: u32, id: u32) -> Option<u128> {
+			TemplateModule::total_supply_at(asset, id)
+		}
+
+		fn number_of(who: AccountId) -> Option<u32> {
+			TemplateModule::latest_number(&who)
+		}
+
+		fn numbers(
+			who: AccountId,
+			start: u32,
+			limit: u32,
+		) -> Vec<pallet_template_rpc_runtime_api::NumberEntry<BlockNumber>> {
+			TemplateModule::numbers_page(&who, start, limit)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,